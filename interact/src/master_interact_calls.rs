@@ -0,0 +1,905 @@
+use crate::master_interact_cli::*;
+use crate::master_interact_output::{self as output, Printer};
+use master::{
+    airdrop::{Airdrop, ProxyTrait as _},
+    allocation::{ProxyTrait as _, SupplyAllocation},
+    anti_snipe::{AntiSnipeConfig, ProxyTrait as _},
+    bonding_overrides::{BondingFilter, BondingParam, BondingParamPush, ProxyTrait as _},
+    config::{FactoryConfig, GasSchedule, ProxyTrait as _},
+    creator::ProxyTrait as _,
+    creator_fees::{CreatorFeesHistory, ProxyTrait as _},
+    curve::{CurveKind, CurveParams, ProxyTrait as _},
+    factory::{BondingParams, PairContractData, PairContractMetadata, ProxyTrait as _},
+    graduation::{GraduationPolicy, LpLock, LpPolicy, ProxyTrait as _},
+    metadata::{ProxyTrait as _, TokenMetadata},
+    migration::{MigrationCursor, ProxyTrait as _},
+    profile::{ProxyTrait as _, DEFAULT_LAUNCH_PROFILE},
+    ranking::{ProxyTrait as _, RankingEntry},
+    rate_limit::{LaunchQuota, ProxyTrait as _},
+    schedule::{ProxyTrait as _, UpcomingLaunch},
+    stats::{PlatformStats, ProxyTrait as _},
+    treasury::{FeeAccount, FeeSource, ProxyTrait as _},
+    vesting::{CreatorBuyVesting, ProxyTrait as _, VestingAmounts, VestingSchedule},
+    ProxyTrait as _,
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    bech32,
+    multiversx_sc::{
+        api::UPGRADE_CONTRACT_FUNC_NAME,
+        codec::multi_types::OptionalValue,
+        types::{
+            BigUint, CodeMetadata, ContractCall, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment,
+            EsdtTokenPayment, ManagedAddress, ManagedBuffer, ManagedByteArray, MultiValueEncoded,
+            TokenIdentifier,
+        },
+    },
+    scenario_model::{BytesValue, ScCallStep, ScDeployStep, ScQueryStep},
+    ContractInfo,
+};
+
+pub type MasterContract = ContractInfo<master::Proxy<StaticApi>>;
+
+pub const DEPLOY_GAS_LIMIT: u64 = 200_000_000;
+pub const CALL_GAS_LIMIT: u64 = 30_000_000;
+pub const BONDING_CALL_GAS_LIMIT: u64 = 100_000_000;
+/// What `getNewTokenGasLimit` returns for the default gas schedule.
+pub const NEW_TOKEN_GAS_LIMIT: u64 = 170_000_000;
+
+/// A command turned into the step that runs it, along with the printer of its results. The
+/// sender is left to whoever runs the step, the interactor on a real network or the scenario
+/// tests in the debugger.
+pub enum MasterStep {
+    Call(ScCallStep, Printer),
+    Query(ScQueryStep, Printer),
+}
+
+pub fn deploy_step(master: &mut MasterContract, args: &DeployArgs) -> ScDeployStep {
+    let contract_deploy = master.init(
+        address(&args.pair_template_address),
+        amount(args.token_supply),
+        address(&args.fees_collector),
+        amount(args.new_token_fee),
+        amount(args.initial_virtual_liquidity),
+        token(&args.initial_token_to_buy_with),
+        address(&args.oracle_address),
+        amount(args.max_market_cap),
+        address(&args.jeetdex_router_sc_address),
+        amount(args.issue_token_cost),
+        address(&args.wegld_unwrap_sc),
+        amount(args.reach_jeetdex_fee),
+    );
+    ScDeployStep::new()
+        .call(contract_deploy)
+        .gas_limit(DEPLOY_GAS_LIMIT)
+        .into()
+}
+
+/// The upgrade goes through the `upgradeContract` builtin function, which replaces the code
+/// and then runs the `#[upgrade]` hook of the new one. The master keeps the metadata it is
+/// deployed with.
+pub fn upgrade_step(master: &MasterContract, code: &BytesValue) -> ScCallStep {
+    ScCallStep::new()
+        .to(master)
+        .function(UPGRADE_CONTRACT_FUNC_NAME)
+        .argument(code)
+        .argument(CodeMetadata::all().to_byte_array().as_slice())
+        .gas_limit(DEPLOY_GAS_LIMIT)
+}
+
+pub fn master_step(master: &mut MasterContract, command: &MasterCommand) -> MasterStep {
+    match command {
+        MasterCommand::Pause(args) => call(
+            master.pause(address(&args.address)),
+            BONDING_CALL_GAS_LIMIT,
+            output::nothing,
+        ),
+        MasterCommand::Resume(args) => call(
+            master.resume(address(&args.address)),
+            BONDING_CALL_GAS_LIMIT,
+            output::nothing,
+        ),
+        MasterCommand::SetRouter(args) => call(
+            master.set_jeetdex_router(address(&args.address), address(&args.router_address)),
+            BONDING_CALL_GAS_LIMIT,
+            output::nothing,
+        ),
+        MasterCommand::NewToken(args) => new_token_step(master, args),
+        MasterCommand::UpgradeToken(args) => call(
+            master.upgrade_pair_endpoint(token(&args.first_token_id), token(&args.second_token_id)),
+            DEPLOY_GAS_LIMIT,
+            output::nothing,
+        ),
+        MasterCommand::Stats => query(
+            master.get_platform_stats(),
+            output::single::<PlatformStats<StaticApi>>,
+        ),
+        MasterCommand::Config(command) => config_step(master, command),
+        MasterCommand::Gas(command) => gas_step(master, command),
+        MasterCommand::Bondings(command) => bondings_step(master, command),
+        MasterCommand::Allowlist(command) => allowlist_step(master, command),
+        MasterCommand::Blocklist(command) => blocklist_step(master, command),
+        MasterCommand::Creator(command) => creator_step(master, command),
+        MasterCommand::CreatorFees(command) => creator_fees_step(master, command),
+        MasterCommand::Graduation(command) => graduation_step(master, command),
+        MasterCommand::Metadata(command) => metadata_step(master, command),
+        MasterCommand::Migrations(command) => migrations_step(master, command),
+        MasterCommand::Profiles(command) => profiles_step(master, command),
+        MasterCommand::AntiSnipe(command) => anti_snipe_step(master, command),
+        MasterCommand::Ranking(command) => ranking_step(master, command),
+        MasterCommand::RateLimits(command) => rate_limits_step(master, command),
+        MasterCommand::Schedule(command) => schedule_step(master, command),
+        MasterCommand::Vesting(command) => vesting_step(master, command),
+        MasterCommand::Allocation(command) => allocation_step(master, command),
+        MasterCommand::Airdrop(command) => airdrop_step(master, command),
+        MasterCommand::Curve(command) => curve_step(master, command),
+        MasterCommand::BondingParams(command) => bonding_params_step(master, command),
+        MasterCommand::Treasury(command) => treasury_step(master, command),
+    }
+}
+
+/// The optional arguments are always sent, as `newToken` cannot skip one to set the next.
+fn new_token_step(master: &mut MasterContract, args: &NewTokenArgs) -> MasterStep {
+    let contract_call = master.create_new_token_endpoint(
+        ManagedBuffer::<StaticApi>::from(args.display_name.as_str()),
+        ManagedBuffer::<StaticApi>::from(args.ticker.as_str()),
+        ManagedBuffer::<StaticApi>::from(args.db_id.as_str()),
+        args.token_creator_buy,
+        OptionalValue::Some(token_metadata(&args.metadata)),
+        OptionalValue::Some(args.start_timestamp),
+        OptionalValue::Some(args.launch_profile.unwrap_or(DEFAULT_LAUNCH_PROFILE)),
+    );
+    let step = ScCallStep::new()
+        .call(contract_call)
+        .egld_value(args.fee)
+        .gas_limit(NEW_TOKEN_GAS_LIMIT);
+    MasterStep::Call(step.into(), output::nothing)
+}
+
+fn config_step(master: &mut MasterContract, command: &ConfigCommand) -> MasterStep {
+    match command {
+        ConfigCommand::Get => query(
+            master.get_config(),
+            output::single::<FactoryConfig<StaticApi>>,
+        ),
+        ConfigCommand::Set(args) => owner_call(master.set_config(factory_config(args))),
+        ConfigCommand::State => query(master.state(), output::single::<bool>),
+        ConfigCommand::TokenSupply => {
+            query(master.token_supply(), output::single::<BigUint<StaticApi>>)
+        }
+        ConfigCommand::NewTokenFee => {
+            query(master.new_token_fee(), output::single::<BigUint<StaticApi>>)
+        }
+        ConfigCommand::BondingTemplate => query(
+            master.pair_template_address(),
+            output::single::<ManagedAddress<StaticApi>>,
+        ),
+        ConfigCommand::SetFeesCollector(args) => {
+            owner_call(master.set_fees_collector(address(&args.address)))
+        }
+        ConfigCommand::SetIvl(args) => {
+            owner_call(master.set_initial_virtual_liquidity(amount(args.amount)))
+        }
+        ConfigCommand::SetTokenSupply(args) => {
+            owner_call(master.set_token_supply(amount(args.amount)))
+        }
+        ConfigCommand::SetNewTokenFee(args) => {
+            owner_call(master.set_new_token_fee(amount(args.amount)))
+        }
+        ConfigCommand::SetMaxMarketCap(args) => {
+            owner_call(master.set_max_market_cap(amount(args.amount)))
+        }
+        ConfigCommand::SetBondingTemplate(args) => {
+            owner_call(master.set_bonding_template_address(address(&args.address)))
+        }
+    }
+}
+
+fn gas_step(master: &mut MasterContract, command: &GasCommand) -> MasterStep {
+    match command {
+        GasCommand::Get => query(master.get_gas_schedule(), output::single::<GasSchedule>),
+        GasCommand::Set(args) => owner_call(master.set_gas_schedule(gas_schedule(args))),
+        GasCommand::NewTokenLimit => query(master.get_new_token_gas_limit(), output::single::<u64>),
+    }
+}
+
+fn bondings_step(master: &mut MasterContract, command: &BondingsCommand) -> MasterStep {
+    match command {
+        BondingsCommand::List => query(
+            master.all_bondings(),
+            output::variadic::<ManagedAddress<StaticApi>>,
+        ),
+        BondingsCommand::Data => query(
+            master.get_all_pair_contract_data(),
+            output::variadic::<PairContractData<StaticApi>>,
+        ),
+        BondingsCommand::Metadata => query(
+            master.get_all_pair_contract_metadata(),
+            output::variadic::<PairContractMetadata<StaticApi>>,
+        ),
+        BondingsCommand::Creator(args) => query(
+            master.bonding_creator(address(&args.address)),
+            output::single::<ManagedAddress<StaticApi>>,
+        ),
+    }
+}
+
+fn allowlist_step(master: &mut MasterContract, command: &AllowlistCommand) -> MasterStep {
+    match command {
+        AllowlistCommand::Add(args) => {
+            owner_call(master.add_to_allowlist(addresses(&args.addresses)))
+        }
+        AllowlistCommand::Remove(args) => {
+            owner_call(master.remove_from_allowlist(addresses(&args.addresses)))
+        }
+        AllowlistCommand::Get => query(
+            master.creator_allowlist(),
+            output::variadic::<ManagedAddress<StaticApi>>,
+        ),
+        AllowlistCommand::Check(args) => query(
+            master.is_allowlisted(address(&args.address)),
+            output::single::<bool>,
+        ),
+        AllowlistCommand::SetEnabled(args) => {
+            owner_call(master.set_allowlist_enabled(args.enabled))
+        }
+        AllowlistCommand::Enabled => query(master.allowlist_enabled(), output::single::<bool>),
+    }
+}
+
+fn blocklist_step(master: &mut MasterContract, command: &BlocklistCommand) -> MasterStep {
+    match command {
+        BlocklistCommand::Add(args) => {
+            owner_call(master.add_to_blocklist(addresses(&args.addresses)))
+        }
+        BlocklistCommand::Remove(args) => {
+            owner_call(master.remove_from_blocklist(addresses(&args.addresses)))
+        }
+        BlocklistCommand::Get => query(
+            master.creator_blocklist(),
+            output::variadic::<ManagedAddress<StaticApi>>,
+        ),
+        BlocklistCommand::Check(args) => query(
+            master.is_blocklisted(address(&args.address)),
+            output::single::<bool>,
+        ),
+    }
+}
+
+fn creator_step(master: &mut MasterContract, command: &CreatorCommand) -> MasterStep {
+    match command {
+        CreatorCommand::ProposeTransfer(args) => owner_call(
+            master.propose_creator_transfer(token(&args.token_id), address(&args.new_creator)),
+        ),
+        CreatorCommand::CancelTransfer(args) => {
+            owner_call(master.cancel_creator_transfer(token(&args.token_id)))
+        }
+        CreatorCommand::AcceptTransfer(args) => {
+            owner_call(master.accept_creator_transfer(token(&args.token_id)))
+        }
+        CreatorCommand::Get(args) => query(
+            master.get_token_creator(token(&args.token_id)),
+            output::single::<ManagedAddress<StaticApi>>,
+        ),
+        CreatorCommand::Pending(args) => query(
+            master.get_pending_creator(token(&args.token_id)),
+            output::optional::<ManagedAddress<StaticApi>>,
+        ),
+    }
+}
+
+fn creator_fees_step(master: &mut MasterContract, command: &CreatorFeesCommand) -> MasterStep {
+    match command {
+        CreatorFeesCommand::SetShare(args) => owner_call(master.set_creator_fee_share(args.value)),
+        CreatorFeesCommand::Share => query(master.creator_fee_share(), output::single::<u64>),
+        CreatorFeesCommand::Claim => call(
+            master.claim_creator_fees(),
+            CALL_GAS_LIMIT,
+            output::variadic::<EgldOrEsdtTokenPayment<StaticApi>>,
+        ),
+        CreatorFeesCommand::Claimable(args) => query(
+            master.get_claimable_creator_fees(address(&args.address)),
+            output::variadic::<EgldOrEsdtTokenPayment<StaticApi>>,
+        ),
+        CreatorFeesCommand::History(args) => query(
+            master.get_creator_fees_history(address(&args.address)),
+            output::variadic::<CreatorFeesHistory<StaticApi>>,
+        ),
+    }
+}
+
+fn graduation_step(master: &mut MasterContract, command: &GraduationCommand) -> MasterStep {
+    match command {
+        GraduationCommand::SetDexPair(args) => owner_call(master.set_dex_pair_for_bonding(
+            address(&args.bonding_address),
+            address(&args.dex_pair_address),
+        )),
+        GraduationCommand::SetPolicy(args) => owner_call(master.set_profile_graduation_policy(
+            args.profile_id,
+            lp_policy(args.lp_policy),
+            args.lock_epochs,
+        )),
+        GraduationCommand::ClearPolicy(args) => {
+            owner_call(master.clear_profile_graduation_policy(args.profile_id))
+        }
+        GraduationCommand::ReleaseLp(args) => {
+            owner_call(master.release_locked_lp(token(&args.token_id)))
+        }
+        GraduationCommand::DexPairForToken(args) => query(
+            master.get_dex_pair_for_token(token(&args.token_id)),
+            output::optional::<ManagedAddress<StaticApi>>,
+        ),
+        GraduationCommand::LpLockForToken(args) => query(
+            master.get_lp_lock_for_token(token(&args.token_id)),
+            output::optional::<LpLock<StaticApi>>,
+        ),
+        GraduationCommand::DexPair(args) => query(
+            master.dex_pair(address(&args.address)),
+            output::single::<ManagedAddress<StaticApi>>,
+        ),
+        GraduationCommand::Policy(args) => query(
+            master.profile_graduation_policy(args.profile_id),
+            output::single::<GraduationPolicy>,
+        ),
+        GraduationCommand::BondingPolicy(args) => query(
+            master.bonding_graduation_policy(address(&args.address)),
+            output::single::<GraduationPolicy>,
+        ),
+        GraduationCommand::LpLock(args) => query(
+            master.lp_lock(address(&args.address)),
+            output::single::<LpLock<StaticApi>>,
+        ),
+        GraduationCommand::BurnedLp(args) => query(
+            master.burned_lp(address(&args.address)),
+            output::single::<EsdtTokenPayment<StaticApi>>,
+        ),
+    }
+}
+
+fn metadata_step(master: &mut MasterContract, command: &MetadataCommand) -> MasterStep {
+    match command {
+        MetadataCommand::Set(args) => owner_call(
+            master.set_token_metadata(token(&args.token_id), token_metadata(&args.metadata)),
+        ),
+        MetadataCommand::Moderate(args) => owner_call(master.moderate_token_metadata(
+            token(&args.token_id),
+            token_metadata(&args.metadata),
+            args.lock,
+        )),
+        MetadataCommand::Get(args) => query(
+            master.token_metadata(token(&args.token_id)),
+            output::single::<TokenMetadata<StaticApi>>,
+        ),
+        MetadataCommand::Locked(args) => query(
+            master.token_metadata_locked(token(&args.token_id)),
+            output::single::<bool>,
+        ),
+    }
+}
+
+fn migrations_step(master: &mut MasterContract, command: &MigrationsCommand) -> MasterStep {
+    match command {
+        MigrationsCommand::Run(args) => call(
+            master.run_migrations(args.max_items as usize),
+            BONDING_CALL_GAS_LIMIT,
+            output::single::<u32>,
+        ),
+        MigrationsCommand::Cursor => query(
+            master.migration_cursor(),
+            output::single::<MigrationCursor<StaticApi>>,
+        ),
+        MigrationsCommand::StorageVersion => query(master.storage_version(), output::single::<u32>),
+    }
+}
+
+fn profiles_step(master: &mut MasterContract, command: &ProfilesCommand) -> MasterStep {
+    match command {
+        ProfilesCommand::Add(args) => owner_call(master.add_launch_profile(args.profile_id)),
+        ProfilesCommand::Remove(args) => owner_call(master.remove_launch_profile(args.profile_id)),
+        ProfilesCommand::List => query(master.launch_profiles(), output::variadic::<u32>),
+        ProfilesCommand::OfBonding(args) => query(
+            master.bonding_launch_profile(address(&args.address)),
+            output::single::<u32>,
+        ),
+    }
+}
+
+fn anti_snipe_step(master: &mut MasterContract, command: &AntiSnipeCommand) -> MasterStep {
+    match command {
+        AntiSnipeCommand::Set(args) => owner_call(master.set_profile_anti_snipe(
+            args.profile_id,
+            args.window_blocks,
+            amount(args.max_buy_per_address),
+            args.fee_percent,
+        )),
+        AntiSnipeCommand::Clear(args) => {
+            owner_call(master.clear_profile_anti_snipe(args.profile_id))
+        }
+        AntiSnipeCommand::Get(args) => query(
+            master.profile_anti_snipe(args.profile_id),
+            output::single::<AntiSnipeConfig<StaticApi>>,
+        ),
+        AntiSnipeCommand::OfBonding(args) => query(
+            master.bonding_anti_snipe(address(&args.address)),
+            output::single::<AntiSnipeConfig<StaticApi>>,
+        ),
+    }
+}
+
+fn ranking_step(master: &mut MasterContract, command: &RankingCommand) -> MasterStep {
+    match command {
+        RankingCommand::SetSize(args) => owner_call(master.set_ranking_size(args.size as usize)),
+        RankingCommand::Size => query(master.ranking_size(), output::single::<u32>),
+        RankingCommand::TopMarketCap => query(
+            master.get_top_by_market_cap_progress(),
+            output::variadic::<RankingEntry<StaticApi>>,
+        ),
+        RankingCommand::TopVolume => query(
+            master.get_top_by_volume(),
+            output::variadic::<RankingEntry<StaticApi>>,
+        ),
+        RankingCommand::Volume24h(args) => query(
+            master.get_volume_24h_view(address(&args.address)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        RankingCommand::LastMarketCap(args) => query(
+            master.last_market_cap(address(&args.address)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+    }
+}
+
+fn rate_limits_step(master: &mut MasterContract, command: &RateLimitsCommand) -> MasterStep {
+    match command {
+        RateLimitsCommand::Set(args) => owner_call(master.set_launch_rate_limits(
+            args.launch_window_rounds,
+            args.max_launches_per_window,
+            args.max_launches_per_block,
+        )),
+        RateLimitsCommand::AddExempt(args) => {
+            owner_call(master.add_rate_limit_exempt(addresses(&args.addresses)))
+        }
+        RateLimitsCommand::RemoveExempt(args) => {
+            owner_call(master.remove_rate_limit_exempt(addresses(&args.addresses)))
+        }
+        RateLimitsCommand::Exempt => query(
+            master.rate_limit_exempt(),
+            output::variadic::<ManagedAddress<StaticApi>>,
+        ),
+        RateLimitsCommand::Quota(args) => query(
+            master.get_remaining_launch_quota(address(&args.address)),
+            output::single::<LaunchQuota>,
+        ),
+        RateLimitsCommand::WindowRounds => {
+            query(master.launch_window_rounds(), output::single::<u64>)
+        }
+        RateLimitsCommand::MaxPerWindow => {
+            query(master.max_launches_per_window(), output::single::<u64>)
+        }
+        RateLimitsCommand::MaxPerBlock => {
+            query(master.max_launches_per_block(), output::single::<u64>)
+        }
+    }
+}
+
+fn schedule_step(master: &mut MasterContract, command: &ScheduleCommand) -> MasterStep {
+    match command {
+        ScheduleCommand::SetMaxStartDelay(args) => {
+            owner_call(master.set_max_start_delay(args.value))
+        }
+        ScheduleCommand::SetCancelRefundPercent(args) => {
+            owner_call(master.set_cancel_refund_percent(args.value))
+        }
+        ScheduleCommand::ReleaseLaunchFee(args) => {
+            owner_call(master.release_launch_fee(token(&args.token_id)))
+        }
+        ScheduleCommand::Cancel(args) => call(
+            master.cancel_scheduled_launch(token(&args.token_id)),
+            BONDING_CALL_GAS_LIMIT,
+            output::nothing,
+        ),
+        ScheduleCommand::Upcoming => query(
+            master.get_upcoming_launches(),
+            output::variadic::<UpcomingLaunch<StaticApi>>,
+        ),
+        ScheduleCommand::MaxStartDelay => query(master.max_start_delay(), output::single::<u64>),
+        ScheduleCommand::CancelRefundPercent => {
+            query(master.cancel_refund_percent(), output::single::<u64>)
+        }
+        ScheduleCommand::StartTimestamp(args) => query(
+            master.launch_start_timestamp(address(&args.address)),
+            output::single::<u64>,
+        ),
+        ScheduleCommand::Cancelled(args) => query(
+            master.cancelled_launch(address(&args.address)),
+            output::single::<bool>,
+        ),
+        ScheduleCommand::BurnedSupply(args) => query(
+            master.cancelled_launch_burned_supply(address(&args.address)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+    }
+}
+
+fn vesting_step(master: &mut MasterContract, command: &VestingCommand) -> MasterStep {
+    match command {
+        VestingCommand::SetProfile(args) => owner_call(master.set_profile_creator_buy_vesting(
+            args.profile_id,
+            args.cliff_duration,
+            args.vesting_duration,
+        )),
+        VestingCommand::ClearProfile(args) => {
+            owner_call(master.clear_profile_creator_buy_vesting(args.profile_id))
+        }
+        VestingCommand::Profile(args) => query(
+            master.profile_creator_buy_vesting(args.profile_id),
+            output::single::<CreatorBuyVesting>,
+        ),
+        VestingCommand::Lock(args) => {
+            let contract_call =
+                master.lock_creator_tokens(args.cliff_duration, args.vesting_duration);
+            let step = ScCallStep::new()
+                .call(contract_call)
+                .esdt_transfer(esdt_expr(&args.token_id).as_str(), 0, args.amount)
+                .gas_limit(CALL_GAS_LIMIT);
+            MasterStep::Call(step.into(), output::nothing)
+        }
+        VestingCommand::Claim(args) => call(
+            master.claim_vested_tokens(token(&args.token_id)),
+            CALL_GAS_LIMIT,
+            output::single::<BigUint<StaticApi>>,
+        ),
+        VestingCommand::Schedules(args) => query(
+            master.get_vesting_schedules(token(&args.token_id)),
+            output::variadic::<VestingSchedule<StaticApi>>,
+        ),
+        VestingCommand::Amounts(args) => query(
+            master.get_vesting_amounts(token(&args.token_id)),
+            output::single::<VestingAmounts<StaticApi>>,
+        ),
+    }
+}
+
+fn allocation_step(master: &mut MasterContract, command: &AllocationCommand) -> MasterStep {
+    match command {
+        AllocationCommand::SetProfile(args) => owner_call(master.set_profile_supply_allocation(
+            args.profile_id,
+            SupplyAllocation {
+                dex_reserve_percent: args.dex_reserve_percent,
+                airdrop_percent: args.airdrop_percent,
+                treasury_percent: args.treasury_percent,
+                treasury_address: address(&args.treasury_address),
+                dev_percent: args.dev_percent,
+                dev_cliff_duration: args.dev_cliff_duration,
+                dev_vesting_duration: args.dev_vesting_duration,
+            },
+        )),
+        AllocationCommand::ClearProfile(args) => {
+            owner_call(master.clear_profile_supply_allocation(args.profile_id))
+        }
+        AllocationCommand::Profile(args) => query(
+            master.profile_supply_allocation(args.profile_id),
+            output::single::<SupplyAllocation<StaticApi>>,
+        ),
+        AllocationCommand::DexReserve(args) => query(
+            master.dex_liquidity_reserve(token(&args.token_id)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        AllocationCommand::AirdropReserve(args) => query(
+            master.airdrop_reserve(token(&args.token_id)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+    }
+}
+
+fn airdrop_step(master: &mut MasterContract, command: &AirdropCommand) -> MasterStep {
+    match command {
+        AirdropCommand::Create(args) => {
+            let contract_call = master.create_airdrop(
+                token(&args.token_id),
+                hash(&args.merkle_root),
+                args.expiry_timestamp,
+            );
+            let step = ScCallStep::new()
+                .call(contract_call)
+                .esdt_transfer(esdt_expr(&args.token_id).as_str(), 0, args.amount)
+                .gas_limit(CALL_GAS_LIMIT);
+            MasterStep::Call(step.into(), output::single::<u64>)
+        }
+        AirdropCommand::Claim(args) => {
+            let mut proof = MultiValueEncoded::new();
+            for node in &args.proof {
+                proof.push(hash(node));
+            }
+            owner_call(master.claim_airdrop(args.airdrop_id, amount(args.amount), proof))
+        }
+        AirdropCommand::Reclaim(args) => owner_call(master.reclaim_airdrop(args.airdrop_id)),
+        AirdropCommand::Get(args) => query(
+            master.airdrop(args.airdrop_id),
+            output::single::<Airdrop<StaticApi>>,
+        ),
+        AirdropCommand::Claimed(args) => query(
+            master.airdrop_claimed(args.airdrop_id, address(&args.address)),
+            output::single::<bool>,
+        ),
+        AirdropCommand::OfToken(args) => query(
+            master.token_airdrops(token(&args.token_id)),
+            output::variadic::<u64>,
+        ),
+    }
+}
+
+fn curve_step(master: &mut MasterContract, command: &CurveCommand) -> MasterStep {
+    match command {
+        CurveCommand::SetProfile(args) => owner_call(master.set_profile_curve(
+            args.profile_id,
+            CurveParams {
+                kind: curve_kind(args.kind),
+                initial_price: amount(args.initial_price),
+                price_increment: amount(args.price_increment),
+                growth_percent: args.growth_percent,
+                step_size: amount(args.step_size),
+            },
+        )),
+        CurveCommand::ClearProfile(args) => owner_call(master.clear_profile_curve(args.profile_id)),
+        CurveCommand::Profile(args) => query(
+            master.profile_curve(args.profile_id),
+            output::single::<CurveParams<StaticApi>>,
+        ),
+        CurveCommand::OfBonding(args) => query(
+            master.bonding_curve(address(&args.address)),
+            output::single::<CurveParams<StaticApi>>,
+        ),
+        CurveCommand::Supply(args) => query(
+            master.bonding_curve_supply(address(&args.address)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        CurveCommand::Quote(args) => query(
+            master.get_buy_quote(token(&args.token_id), amount(args.amount)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        CurveCommand::Simulate(args) => query(
+            master.simulate_buy_cost(args.profile_id, amount(args.sold), amount(args.amount)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+    }
+}
+
+fn bonding_params_step(master: &mut MasterContract, command: &BondingParamsCommand) -> MasterStep {
+    match command {
+        BondingParamsCommand::Set(args) => call(
+            master.set_bonding_param(address(&args.bonding_address), bonding_param(&args.param)),
+            BONDING_CALL_GAS_LIMIT,
+            output::nothing,
+        ),
+        BondingParamsCommand::SetFor(args) => call(
+            master.set_bonding_param_for_bondings(
+                bonding_param(&args.param),
+                addresses(&args.bonding_addresses),
+            ),
+            BONDING_CALL_GAS_LIMIT,
+            output::nothing,
+        ),
+        BondingParamsCommand::StartPush(args) => {
+            let filter = match args.filter {
+                BondingFilterArg::All => BondingFilter::All,
+                BondingFilterArg::LaunchProfile => BondingFilter::LaunchProfile(
+                    args.profile_id
+                        .expect("the launch-profile filter needs a profile"),
+                ),
+                BondingFilterArg::NotGraduated => BondingFilter::NotGraduated,
+            };
+            owner_call(master.start_bonding_param_push(bonding_param(&args.param), filter))
+        }
+        BondingParamsCommand::ContinuePush(args) => call(
+            master.continue_bonding_param_push(args.max_items as usize),
+            BONDING_CALL_GAS_LIMIT,
+            output::single::<u32>,
+        ),
+        BondingParamsCommand::CancelPush => owner_call(master.cancel_bonding_param_push()),
+        BondingParamsCommand::Push => query(
+            master.get_bonding_param_push(),
+            output::optional::<BondingParamPush<StaticApi>>,
+        ),
+        BondingParamsCommand::OfBonding(args) => query(
+            master.get_bonding_params(address(&args.address)),
+            output::single::<BondingParams<StaticApi>>,
+        ),
+    }
+}
+
+fn treasury_step(master: &mut MasterContract, command: &TreasuryCommand) -> MasterStep {
+    match command {
+        TreasuryCommand::SetPullMode(args) => owner_call(master.set_fees_pull_mode(args.enabled)),
+        TreasuryCommand::PullMode => query(master.fees_pull_mode(), output::single::<bool>),
+        TreasuryCommand::Withdraw(args) => {
+            let mut token_ids = MultiValueEncoded::new();
+            for token_id in &args.token_ids {
+                token_ids.push(egld_or_esdt(token_id));
+            }
+            call(
+                master.withdraw_fees(token_ids),
+                CALL_GAS_LIMIT,
+                output::variadic::<EgldOrEsdtTokenPayment<StaticApi>>,
+            )
+        }
+        TreasuryCommand::Recover(args) => call(
+            master.recover_stray_funds(
+                egld_or_esdt(&args.token_id),
+                OptionalValue::from(args.to.as_deref().map(address)),
+            ),
+            CALL_GAS_LIMIT,
+            output::single::<BigUint<StaticApi>>,
+        ),
+        TreasuryCommand::StrayBalance(args) => query(
+            master.get_stray_balance(egld_or_esdt(&args.token_id)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        TreasuryCommand::Accounts => query(
+            master.get_fee_accounts(),
+            output::variadic::<FeeAccount<StaticApi>>,
+        ),
+        TreasuryCommand::FeeTokens => query(
+            master.fee_tokens(),
+            output::variadic::<EgldOrEsdtTokenIdentifier<StaticApi>>,
+        ),
+        TreasuryCommand::Accrued(args) => query(
+            master.fees_accrued(fee_source(args.source), egld_or_esdt(&args.token_id)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        TreasuryCommand::Withdrawn(args) => query(
+            master.fees_withdrawn(egld_or_esdt(&args.token_id)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        TreasuryCommand::Withdrawable(args) => query(
+            master.withdrawable_fees(egld_or_esdt(&args.token_id)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+        TreasuryCommand::OwedTokens => query(
+            master.owed_tokens(),
+            output::variadic::<EgldOrEsdtTokenIdentifier<StaticApi>>,
+        ),
+        TreasuryCommand::OwedBalance(args) => query(
+            master.owed_balance(egld_or_esdt(&args.token_id)),
+            output::single::<BigUint<StaticApi>>,
+        ),
+    }
+}
+
+fn call<CC: ContractCall<StaticApi>>(
+    contract_call: CC,
+    gas_limit: u64,
+    printer: Printer,
+) -> MasterStep {
+    let step = ScCallStep::new().call(contract_call).gas_limit(gas_limit);
+    MasterStep::Call(step.into(), printer)
+}
+
+/// Settings and other calls that return nothing and stay within the master.
+fn owner_call<CC: ContractCall<StaticApi>>(contract_call: CC) -> MasterStep {
+    call(contract_call, CALL_GAS_LIMIT, output::nothing)
+}
+
+fn query<CC: ContractCall<StaticApi>>(contract_call: CC, printer: Printer) -> MasterStep {
+    MasterStep::Query(ScQueryStep::new().call(contract_call).into(), printer)
+}
+
+pub fn address(bech32_address: &str) -> ManagedAddress<StaticApi> {
+    ManagedAddress::from_address(&bech32::decode(bech32_address))
+}
+
+fn addresses(
+    bech32_addresses: &[String],
+) -> MultiValueEncoded<StaticApi, ManagedAddress<StaticApi>> {
+    let mut result = MultiValueEncoded::new();
+    for bech32_address in bech32_addresses {
+        result.push(address(bech32_address));
+    }
+    result
+}
+
+fn amount(value: u128) -> BigUint<StaticApi> {
+    BigUint::from(value)
+}
+
+fn token(token_id: &str) -> TokenIdentifier<StaticApi> {
+    TokenIdentifier::from(token_id)
+}
+
+fn egld_or_esdt(token_id: &str) -> EgldOrEsdtTokenIdentifier<StaticApi> {
+    if token_id == "EGLD" {
+        EgldOrEsdtTokenIdentifier::egld()
+    } else {
+        EgldOrEsdtTokenIdentifier::esdt(token(token_id))
+    }
+}
+
+fn esdt_expr(token_id: &str) -> String {
+    format!("str:{token_id}")
+}
+
+fn hash(hex_hash: &str) -> ManagedByteArray<StaticApi, 32> {
+    let bytes: [u8; 32] = hex::decode(hex_hash)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .expect("hashes must be 32 hex encoded bytes");
+    ManagedByteArray::new_from_bytes(&bytes)
+}
+
+fn token_metadata(args: &TokenMetadataArgs) -> TokenMetadata<StaticApi> {
+    TokenMetadata {
+        description: ManagedBuffer::from(args.description.as_str()),
+        image_uri: ManagedBuffer::from(args.image_uri.as_str()),
+        image_hash: ManagedBuffer::from(args.image_hash.as_str()),
+        website: ManagedBuffer::from(args.website.as_str()),
+        twitter: ManagedBuffer::from(args.twitter.as_str()),
+        telegram: ManagedBuffer::from(args.telegram.as_str()),
+    }
+}
+
+fn gas_schedule(args: &GasScheduleArgs) -> GasSchedule {
+    GasSchedule {
+        new_token: args.new_token,
+        create_bonding: args.create_bonding,
+        register_router: args.register_router,
+        set_token_identifier: args.set_token_identifier,
+        callback: args.callback,
+    }
+}
+
+fn factory_config(args: &FactoryConfigArgs) -> FactoryConfig<StaticApi> {
+    let config = &args.deploy;
+    FactoryConfig {
+        pair_template_address: address(&config.pair_template_address),
+        token_supply: amount(config.token_supply),
+        fees_collector: address(&config.fees_collector),
+        new_token_fee: amount(config.new_token_fee),
+        initial_virtual_liquidity: amount(config.initial_virtual_liquidity),
+        allowed_token: token(&config.initial_token_to_buy_with),
+        oracle_address: address(&config.oracle_address),
+        max_market_cap: amount(config.max_market_cap),
+        jeetdex_router_sc_address: address(&config.jeetdex_router_sc_address),
+        issue_token_cost: amount(config.issue_token_cost),
+        wegld_unwrap_sc: address(&config.wegld_unwrap_sc),
+        reach_jeetdex_fee: amount(config.reach_jeetdex_fee),
+        gas_schedule: gas_schedule(&args.gas_schedule),
+    }
+}
+
+fn bonding_param(args: &BondingParamArgs) -> BondingParam<StaticApi> {
+    let value_amount = || amount(args.value.parse().expect("the value must be an amount"));
+    match args.param {
+        BondingParamArg::FeesCollector => BondingParam::FeesCollector(address(&args.value)),
+        BondingParamArg::OracleAddress => BondingParam::OracleAddress(address(&args.value)),
+        BondingParamArg::MaxMarketCap => BondingParam::MaxMarketCap(value_amount()),
+        BondingParamArg::ReachJeetdexFee => BondingParam::ReachJeetdexFee(value_amount()),
+        BondingParamArg::WegldUnwrapSc => BondingParam::WegldUnwrapSc(address(&args.value)),
+    }
+}
+
+fn lp_policy(arg: LpPolicyArg) -> LpPolicy {
+    match arg {
+        LpPolicyArg::Burn => LpPolicy::Burn,
+        LpPolicyArg::Lock => LpPolicy::Lock,
+        LpPolicyArg::Treasury => LpPolicy::Treasury,
+    }
+}
+
+fn curve_kind(arg: CurveKindArg) -> CurveKind {
+    match arg {
+        CurveKindArg::ConstantProduct => CurveKind::ConstantProduct,
+        CurveKindArg::Linear => CurveKind::Linear,
+        CurveKindArg::Exponential => CurveKind::Exponential,
+        CurveKindArg::Stepwise => CurveKind::Stepwise,
+    }
+}
+
+fn fee_source(arg: FeeSourceArg) -> FeeSource {
+    match arg {
+        FeeSourceArg::LaunchFee => FeeSource::LaunchFee,
+        FeeSourceArg::ScheduledLaunchFee => FeeSource::ScheduledLaunchFee,
+        FeeSourceArg::CancelledLaunchFee => FeeSource::CancelledLaunchFee,
+        FeeSourceArg::SwapFees => FeeSource::SwapFees,
+        FeeSourceArg::GraduationLp => FeeSource::GraduationLp,
+    }
+}
@@ -0,0 +1,934 @@
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+
+/// Master Interact CLI
+///
+/// Every endpoint and view of the master has a subcommand, except `depositSwapFees`,
+/// `registerDexPair`, `reportTrade` and `claimDexReserve`, which only the bondings deployed
+/// by the master may call.
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    /// Overrides the gas limit of the transaction
+    #[arg(long = "gas-limit", global = true)]
+    pub gas_limit: Option<u64>,
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+/// Master Interact CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(name = "deploy", about = "Deploy the master contract")]
+    Deploy(DeployArgs),
+    #[command(
+        name = "upgrade",
+        about = "Upgrade the master to the built contract, which leaves it paused"
+    )]
+    Upgrade,
+    #[command(flatten)]
+    Master(MasterCommand),
+}
+
+/// Commands run against a deployed master
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum MasterCommand {
+    #[command(name = "pause", about = "Pause the master or one of its bondings")]
+    Pause(AddressArgs),
+    #[command(name = "resume", about = "Resume the master or one of its bondings")]
+    Resume(AddressArgs),
+    #[command(
+        name = "set-router",
+        about = "Set the JeetDex router of the master or of a bonding"
+    )]
+    SetRouter(SetRouterArgs),
+    #[command(
+        name = "new-token",
+        about = "Launch a new token, paying the new token fee"
+    )]
+    NewToken(NewTokenArgs),
+    #[command(
+        name = "upgrade-token",
+        about = "Upgrade the bonding of a token to the current template"
+    )]
+    UpgradeToken(UpgradeTokenArgs),
+    #[command(name = "stats", about = "Show the platform stats")]
+    Stats,
+    #[command(
+        subcommand,
+        name = "config",
+        about = "Read or update the factory config"
+    )]
+    Config(ConfigCommand),
+    #[command(
+        subcommand,
+        name = "gas",
+        about = "Read or update the gas schedule of new launches"
+    )]
+    Gas(GasCommand),
+    #[command(
+        subcommand,
+        name = "bondings",
+        about = "List the bondings deployed by the master"
+    )]
+    Bondings(BondingsCommand),
+    #[command(subcommand, name = "allowlist", about = "Manage the creator allowlist")]
+    Allowlist(AllowlistCommand),
+    #[command(subcommand, name = "blocklist", about = "Manage the creator blocklist")]
+    Blocklist(BlocklistCommand),
+    #[command(
+        subcommand,
+        name = "creator",
+        about = "Transfer the creator role of a token"
+    )]
+    Creator(CreatorCommand),
+    #[command(
+        subcommand,
+        name = "creator-fees",
+        about = "Share and claim the swap fees of creators"
+    )]
+    CreatorFees(CreatorFeesCommand),
+    #[command(
+        subcommand,
+        name = "graduation",
+        about = "Manage the DEX pairs and LP of graduated bondings"
+    )]
+    Graduation(GraduationCommand),
+    #[command(subcommand, name = "metadata", about = "Read or update token metadata")]
+    Metadata(MetadataCommand),
+    #[command(
+        subcommand,
+        name = "migrations",
+        about = "Run the storage migrations after an upgrade"
+    )]
+    Migrations(MigrationsCommand),
+    #[command(subcommand, name = "profiles", about = "Manage the launch profiles")]
+    Profiles(ProfilesCommand),
+    #[command(
+        subcommand,
+        name = "anti-snipe",
+        about = "Manage the anti-snipe settings of launch profiles"
+    )]
+    AntiSnipe(AntiSnipeCommand),
+    #[command(subcommand, name = "ranking", about = "Read the bonding rankings")]
+    Ranking(RankingCommand),
+    #[command(
+        subcommand,
+        name = "rate-limits",
+        about = "Manage the launch rate limits"
+    )]
+    RateLimits(RateLimitsCommand),
+    #[command(subcommand, name = "schedule", about = "Manage scheduled launches")]
+    Schedule(ScheduleCommand),
+    #[command(
+        subcommand,
+        name = "vesting",
+        about = "Manage the vesting of creator tokens"
+    )]
+    Vesting(VestingCommand),
+    #[command(
+        subcommand,
+        name = "allocation",
+        about = "Manage the supply allocation of launch profiles"
+    )]
+    Allocation(AllocationCommand),
+    #[command(
+        subcommand,
+        name = "airdrop",
+        about = "Create and claim Merkle airdrops"
+    )]
+    Airdrop(AirdropCommand),
+    #[command(
+        subcommand,
+        name = "curve",
+        about = "Manage the bonding curves of launch profiles"
+    )]
+    Curve(CurveCommand),
+    #[command(
+        subcommand,
+        name = "bonding-params",
+        about = "Push parameters to live bondings"
+    )]
+    BondingParams(BondingParamsCommand),
+    #[command(
+        subcommand,
+        name = "treasury",
+        about = "Withdraw and account for the fees kept by the master"
+    )]
+    Treasury(TreasuryCommand),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct DeployArgs {
+    #[arg(long = "template")]
+    pub pair_template_address: String,
+    #[arg(long = "token-supply")]
+    pub token_supply: u128,
+    #[arg(long = "fees-collector")]
+    pub fees_collector: String,
+    #[arg(long = "new-token-fee")]
+    pub new_token_fee: u128,
+    #[arg(long = "ivl")]
+    pub initial_virtual_liquidity: u128,
+    #[arg(long = "buy-token")]
+    pub initial_token_to_buy_with: String,
+    #[arg(long = "oracle")]
+    pub oracle_address: String,
+    #[arg(long = "max-market-cap")]
+    pub max_market_cap: u128,
+    #[arg(long = "router")]
+    pub jeetdex_router_sc_address: String,
+    #[arg(long = "issue-token-cost")]
+    pub issue_token_cost: u128,
+    #[arg(long = "wegld-unwrap-sc")]
+    pub wegld_unwrap_sc: String,
+    #[arg(long = "reach-jeetdex-fee")]
+    pub reach_jeetdex_fee: u128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum ConfigCommand {
+    #[command(name = "get", about = "Print the current config")]
+    Get,
+    #[command(name = "set", about = "Replace the whole config")]
+    Set(Box<FactoryConfigArgs>),
+    #[command(name = "state", about = "Print whether the factory is active")]
+    State,
+    #[command(name = "token-supply")]
+    TokenSupply,
+    #[command(name = "new-token-fee")]
+    NewTokenFee,
+    #[command(name = "bonding-template")]
+    BondingTemplate,
+    #[command(name = "set-fees-collector")]
+    SetFeesCollector(AddressArgs),
+    #[command(name = "set-ivl")]
+    SetIvl(AmountArgs),
+    #[command(name = "set-token-supply")]
+    SetTokenSupply(AmountArgs),
+    #[command(name = "set-new-token-fee")]
+    SetNewTokenFee(AmountArgs),
+    #[command(name = "set-max-market-cap")]
+    SetMaxMarketCap(AmountArgs),
+    #[command(name = "set-bonding-template")]
+    SetBondingTemplate(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct FactoryConfigArgs {
+    #[command(flatten)]
+    pub deploy: DeployArgs,
+    #[command(flatten)]
+    pub gas_schedule: GasScheduleArgs,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum GasCommand {
+    #[command(name = "get", about = "Print the gas schedule")]
+    Get,
+    #[command(name = "set", about = "Replace the gas schedule")]
+    Set(GasScheduleArgs),
+    #[command(name = "new-token-limit", about = "Print the gas limit newToken needs")]
+    NewTokenLimit,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct GasScheduleArgs {
+    #[arg(long = "new-token-gas")]
+    pub new_token: u64,
+    #[arg(long = "create-bonding-gas")]
+    pub create_bonding: u64,
+    #[arg(long = "register-router-gas")]
+    pub register_router: u64,
+    #[arg(long = "set-token-identifier-gas")]
+    pub set_token_identifier: u64,
+    #[arg(long = "callback-gas")]
+    pub callback: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum BondingsCommand {
+    #[command(name = "list", about = "Print the bonding addresses")]
+    List,
+    #[command(
+        name = "data",
+        about = "Print the reserves and market cap of every bonding"
+    )]
+    Data,
+    #[command(name = "metadata", about = "Print the token pair of every bonding")]
+    Metadata,
+    #[command(name = "creator", about = "Print the creator of a bonding")]
+    Creator(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum AllowlistCommand {
+    #[command(name = "add")]
+    Add(AddressListArgs),
+    #[command(name = "remove")]
+    Remove(AddressListArgs),
+    #[command(name = "get")]
+    Get,
+    #[command(name = "check")]
+    Check(AddressArgs),
+    #[command(name = "set-enabled")]
+    SetEnabled(SwitchArgs),
+    #[command(name = "enabled")]
+    Enabled,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum BlocklistCommand {
+    #[command(name = "add")]
+    Add(AddressListArgs),
+    #[command(name = "remove")]
+    Remove(AddressListArgs),
+    #[command(name = "get")]
+    Get,
+    #[command(name = "check")]
+    Check(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum CreatorCommand {
+    #[command(name = "propose-transfer")]
+    ProposeTransfer(CreatorTransferArgs),
+    #[command(name = "cancel-transfer")]
+    CancelTransfer(TokenArgs),
+    #[command(name = "accept-transfer")]
+    AcceptTransfer(TokenArgs),
+    #[command(name = "get")]
+    Get(TokenArgs),
+    #[command(name = "pending")]
+    Pending(TokenArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct CreatorTransferArgs {
+    pub token_id: String,
+    pub new_creator: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum CreatorFeesCommand {
+    #[command(
+        name = "set-share",
+        about = "Set the share of swap fees creators get, in basis points"
+    )]
+    SetShare(ValueArgs),
+    #[command(name = "share")]
+    Share,
+    #[command(name = "claim", about = "Claim the fees owed to the caller")]
+    Claim,
+    #[command(name = "claimable")]
+    Claimable(AddressArgs),
+    #[command(name = "history")]
+    History(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum GraduationCommand {
+    #[command(name = "set-dex-pair")]
+    SetDexPair(SetDexPairArgs),
+    #[command(name = "set-policy")]
+    SetPolicy(GraduationPolicyArgs),
+    #[command(name = "clear-policy")]
+    ClearPolicy(ProfileArgs),
+    #[command(
+        name = "release-lp",
+        about = "Send the locked LP of a token to its creator"
+    )]
+    ReleaseLp(TokenArgs),
+    #[command(name = "dex-pair-for-token")]
+    DexPairForToken(TokenArgs),
+    #[command(name = "lp-lock-for-token")]
+    LpLockForToken(TokenArgs),
+    #[command(name = "dex-pair")]
+    DexPair(AddressArgs),
+    #[command(name = "policy")]
+    Policy(ProfileArgs),
+    #[command(name = "bonding-policy")]
+    BondingPolicy(AddressArgs),
+    #[command(name = "lp-lock")]
+    LpLock(AddressArgs),
+    #[command(name = "burned-lp")]
+    BurnedLp(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetDexPairArgs {
+    pub bonding_address: String,
+    pub dex_pair_address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct GraduationPolicyArgs {
+    pub profile_id: u32,
+    #[arg(value_enum)]
+    pub lp_policy: LpPolicyArg,
+    #[arg(default_value_t = 0)]
+    pub lock_epochs: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum LpPolicyArg {
+    Burn,
+    Lock,
+    Treasury,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum MetadataCommand {
+    #[command(name = "set")]
+    Set(SetMetadataArgs),
+    #[command(
+        name = "moderate",
+        about = "Replace the metadata of a token, and optionally lock it"
+    )]
+    Moderate(ModerateMetadataArgs),
+    #[command(name = "get")]
+    Get(TokenArgs),
+    #[command(name = "locked")]
+    Locked(TokenArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetMetadataArgs {
+    pub token_id: String,
+    #[command(flatten)]
+    pub metadata: TokenMetadataArgs,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct ModerateMetadataArgs {
+    pub token_id: String,
+    #[command(flatten)]
+    pub metadata: TokenMetadataArgs,
+    #[arg(long = "lock")]
+    pub lock: bool,
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Debug, Args)]
+pub struct TokenMetadataArgs {
+    #[arg(long = "description", default_value = "")]
+    pub description: String,
+    #[arg(long = "image-uri", default_value = "")]
+    pub image_uri: String,
+    #[arg(long = "image-hash", default_value = "")]
+    pub image_hash: String,
+    #[arg(long = "website", default_value = "")]
+    pub website: String,
+    #[arg(long = "twitter", default_value = "")]
+    pub twitter: String,
+    #[arg(long = "telegram", default_value = "")]
+    pub telegram: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum MigrationsCommand {
+    #[command(name = "run")]
+    Run(MaxItemsArgs),
+    #[command(name = "cursor")]
+    Cursor,
+    #[command(name = "storage-version")]
+    StorageVersion,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum ProfilesCommand {
+    #[command(name = "add")]
+    Add(ProfileArgs),
+    #[command(name = "remove")]
+    Remove(ProfileArgs),
+    #[command(name = "list")]
+    List,
+    #[command(name = "of-bonding")]
+    OfBonding(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum AntiSnipeCommand {
+    #[command(name = "set")]
+    Set(AntiSnipeArgs),
+    #[command(name = "clear")]
+    Clear(ProfileArgs),
+    #[command(name = "get")]
+    Get(ProfileArgs),
+    #[command(name = "of-bonding")]
+    OfBonding(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct AntiSnipeArgs {
+    pub profile_id: u32,
+    pub window_blocks: u64,
+    pub max_buy_per_address: u128,
+    pub fee_percent: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum RankingCommand {
+    #[command(name = "set-size")]
+    SetSize(SizeArgs),
+    #[command(name = "size")]
+    Size,
+    #[command(name = "top-market-cap")]
+    TopMarketCap,
+    #[command(name = "top-volume")]
+    TopVolume,
+    #[command(name = "volume-24h")]
+    Volume24h(AddressArgs),
+    #[command(name = "last-market-cap")]
+    LastMarketCap(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum RateLimitsCommand {
+    #[command(name = "set")]
+    Set(RateLimitsArgs),
+    #[command(name = "add-exempt")]
+    AddExempt(AddressListArgs),
+    #[command(name = "remove-exempt")]
+    RemoveExempt(AddressListArgs),
+    #[command(name = "exempt")]
+    Exempt,
+    #[command(name = "quota", about = "Print the launches an address has left")]
+    Quota(AddressArgs),
+    #[command(name = "window-rounds")]
+    WindowRounds,
+    #[command(name = "max-per-window")]
+    MaxPerWindow,
+    #[command(name = "max-per-block")]
+    MaxPerBlock,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct RateLimitsArgs {
+    pub launch_window_rounds: u64,
+    pub max_launches_per_window: u64,
+    pub max_launches_per_block: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum ScheduleCommand {
+    #[command(name = "set-max-start-delay")]
+    SetMaxStartDelay(ValueArgs),
+    #[command(name = "set-cancel-refund-percent")]
+    SetCancelRefundPercent(ValueArgs),
+    #[command(name = "release-launch-fee")]
+    ReleaseLaunchFee(TokenArgs),
+    #[command(name = "cancel")]
+    Cancel(TokenArgs),
+    #[command(name = "upcoming")]
+    Upcoming,
+    #[command(name = "max-start-delay")]
+    MaxStartDelay,
+    #[command(name = "cancel-refund-percent")]
+    CancelRefundPercent,
+    #[command(name = "start-timestamp")]
+    StartTimestamp(AddressArgs),
+    #[command(name = "cancelled")]
+    Cancelled(AddressArgs),
+    #[command(name = "burned-supply")]
+    BurnedSupply(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum VestingCommand {
+    #[command(name = "set-profile")]
+    SetProfile(VestingProfileArgs),
+    #[command(name = "clear-profile")]
+    ClearProfile(ProfileArgs),
+    #[command(name = "profile")]
+    Profile(ProfileArgs),
+    #[command(
+        name = "lock",
+        about = "Lock tokens of the caller in a new vesting schedule"
+    )]
+    Lock(LockTokensArgs),
+    #[command(name = "claim")]
+    Claim(TokenArgs),
+    #[command(name = "schedules")]
+    Schedules(TokenArgs),
+    #[command(name = "amounts")]
+    Amounts(TokenArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct VestingProfileArgs {
+    pub profile_id: u32,
+    pub cliff_duration: u64,
+    pub vesting_duration: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct LockTokensArgs {
+    pub token_id: String,
+    pub amount: u128,
+    pub cliff_duration: u64,
+    pub vesting_duration: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum AllocationCommand {
+    #[command(name = "set-profile")]
+    SetProfile(SupplyAllocationArgs),
+    #[command(name = "clear-profile")]
+    ClearProfile(ProfileArgs),
+    #[command(name = "profile")]
+    Profile(ProfileArgs),
+    #[command(name = "dex-reserve")]
+    DexReserve(TokenArgs),
+    #[command(name = "airdrop-reserve")]
+    AirdropReserve(TokenArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SupplyAllocationArgs {
+    pub profile_id: u32,
+    #[arg(long = "dex-reserve", default_value_t = 0)]
+    pub dex_reserve_percent: u64,
+    #[arg(long = "airdrop", default_value_t = 0)]
+    pub airdrop_percent: u64,
+    #[arg(long = "treasury", default_value_t = 0)]
+    pub treasury_percent: u64,
+    #[arg(long = "treasury-address")]
+    pub treasury_address: String,
+    #[arg(long = "dev", default_value_t = 0)]
+    pub dev_percent: u64,
+    #[arg(long = "dev-cliff", default_value_t = 0)]
+    pub dev_cliff_duration: u64,
+    #[arg(long = "dev-vesting", default_value_t = 0)]
+    pub dev_vesting_duration: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum AirdropCommand {
+    #[command(
+        name = "create",
+        about = "Fund a new airdrop of a token the caller created"
+    )]
+    Create(CreateAirdropArgs),
+    #[command(name = "claim")]
+    Claim(ClaimAirdropArgs),
+    #[command(
+        name = "reclaim",
+        about = "Take back what is left of an expired airdrop"
+    )]
+    Reclaim(AirdropIdArgs),
+    #[command(name = "get")]
+    Get(AirdropIdArgs),
+    #[command(name = "claimed")]
+    Claimed(AirdropClaimedArgs),
+    #[command(name = "of-token")]
+    OfToken(TokenArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct CreateAirdropArgs {
+    pub token_id: String,
+    pub amount: u128,
+    /// Hex encoded Merkle root
+    pub merkle_root: String,
+    pub expiry_timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct ClaimAirdropArgs {
+    pub airdrop_id: u64,
+    pub amount: u128,
+    /// Hex encoded Merkle proof, from the leaf up
+    pub proof: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct AirdropIdArgs {
+    pub airdrop_id: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct AirdropClaimedArgs {
+    pub airdrop_id: u64,
+    pub address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum CurveCommand {
+    #[command(name = "set-profile")]
+    SetProfile(CurveParamsArgs),
+    #[command(name = "clear-profile")]
+    ClearProfile(ProfileArgs),
+    #[command(name = "profile")]
+    Profile(ProfileArgs),
+    #[command(name = "of-bonding")]
+    OfBonding(AddressArgs),
+    #[command(
+        name = "supply",
+        about = "Print the supply a bonding sells along its curve"
+    )]
+    Supply(AddressArgs),
+    #[command(
+        name = "quote",
+        about = "Print the cost of buying an amount of a launched token"
+    )]
+    Quote(TokenAmountArgs),
+    #[command(
+        name = "simulate",
+        about = "Print the cost of a buy on the curve of a profile"
+    )]
+    Simulate(SimulateBuyArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct CurveParamsArgs {
+    pub profile_id: u32,
+    #[arg(long = "kind", value_enum)]
+    pub kind: CurveKindArg,
+    #[arg(long = "initial-price", default_value_t = 0)]
+    pub initial_price: u128,
+    #[arg(long = "price-increment", default_value_t = 0)]
+    pub price_increment: u128,
+    #[arg(long = "growth-percent", default_value_t = 0)]
+    pub growth_percent: u64,
+    #[arg(long = "step-size", default_value_t = 0)]
+    pub step_size: u128,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum CurveKindArg {
+    ConstantProduct,
+    Linear,
+    Exponential,
+    Stepwise,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SimulateBuyArgs {
+    pub profile_id: u32,
+    pub sold: u128,
+    pub amount: u128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum BondingParamsCommand {
+    #[command(name = "set", about = "Set a parameter of one bonding")]
+    Set(SetBondingParamArgs),
+    #[command(name = "set-for", about = "Set a parameter of several bondings")]
+    SetFor(SetBondingParamForArgs),
+    #[command(
+        name = "start-push",
+        about = "Start pushing a parameter to every bonding a filter matches"
+    )]
+    StartPush(StartBondingParamPushArgs),
+    #[command(name = "continue-push")]
+    ContinuePush(MaxItemsArgs),
+    #[command(name = "cancel-push")]
+    CancelPush,
+    #[command(name = "push", about = "Print the push in progress")]
+    Push,
+    #[command(name = "of-bonding")]
+    OfBonding(AddressArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct BondingParamArgs {
+    #[arg(value_enum)]
+    pub param: BondingParamArg,
+    /// Bech32 address or amount, depending on the parameter
+    pub value: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum BondingParamArg {
+    FeesCollector,
+    OracleAddress,
+    MaxMarketCap,
+    ReachJeetdexFee,
+    WegldUnwrapSc,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetBondingParamArgs {
+    pub bonding_address: String,
+    #[command(flatten)]
+    pub param: BondingParamArgs,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetBondingParamForArgs {
+    #[command(flatten)]
+    pub param: BondingParamArgs,
+    #[arg(long = "bondings", required = true, num_args = 1..)]
+    pub bonding_addresses: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct StartBondingParamPushArgs {
+    #[command(flatten)]
+    pub param: BondingParamArgs,
+    #[arg(long = "filter", value_enum, default_value_t = BondingFilterArg::All)]
+    pub filter: BondingFilterArg,
+    /// Launch profile the bondings must use, with `--filter launch-profile`
+    #[arg(long = "profile", required_if_eq("filter", "launch-profile"))]
+    pub profile_id: Option<u32>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum BondingFilterArg {
+    All,
+    LaunchProfile,
+    NotGraduated,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum TreasuryCommand {
+    #[command(name = "set-pull-mode")]
+    SetPullMode(SwitchArgs),
+    #[command(name = "pull-mode")]
+    PullMode,
+    #[command(
+        name = "withdraw",
+        about = "Withdraw the fees of the given tokens to the fees collector"
+    )]
+    Withdraw(TokenListArgs),
+    #[command(name = "recover", about = "Send the balance nobody is owed of a token")]
+    Recover(RecoverArgs),
+    #[command(name = "stray-balance")]
+    StrayBalance(TokenArgs),
+    #[command(name = "accounts")]
+    Accounts,
+    #[command(name = "fee-tokens")]
+    FeeTokens,
+    #[command(name = "accrued")]
+    Accrued(FeesAccruedArgs),
+    #[command(name = "withdrawn")]
+    Withdrawn(TokenArgs),
+    #[command(name = "withdrawable")]
+    Withdrawable(TokenArgs),
+    #[command(name = "owed-tokens")]
+    OwedTokens,
+    #[command(name = "owed-balance")]
+    OwedBalance(TokenArgs),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct RecoverArgs {
+    /// Token identifier, or EGLD
+    pub token_id: String,
+    /// Receiver of the funds, the caller by default
+    #[arg(long = "to")]
+    pub to: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct FeesAccruedArgs {
+    #[arg(value_enum)]
+    pub source: FeeSourceArg,
+    /// Token identifier, or EGLD
+    pub token_id: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum FeeSourceArg {
+    LaunchFee,
+    ScheduledLaunchFee,
+    CancelledLaunchFee,
+    SwapFees,
+    GraduationLp,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct AddressArgs {
+    /// Bech32 address
+    pub address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct AddressListArgs {
+    /// Bech32 addresses
+    #[arg(required = true)]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct AmountArgs {
+    /// Amount, in the smallest denomination
+    pub amount: u128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct ValueArgs {
+    pub value: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SizeArgs {
+    pub size: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct MaxItemsArgs {
+    pub max_items: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SwitchArgs {
+    #[arg(action = ArgAction::Set)]
+    pub enabled: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct ProfileArgs {
+    pub profile_id: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct TokenArgs {
+    pub token_id: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct TokenListArgs {
+    /// Token identifiers, or EGLD
+    #[arg(required = true)]
+    pub token_ids: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct TokenAmountArgs {
+    pub token_id: String,
+    pub amount: u128,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct NewTokenArgs {
+    pub display_name: String,
+    pub ticker: String,
+    #[arg(long = "db-id", default_value = "")]
+    pub db_id: String,
+    /// New token fee paid with the launch, in the smallest denomination
+    #[arg(long = "fee")]
+    pub fee: u128,
+    #[arg(long = "creator-buy")]
+    pub token_creator_buy: bool,
+    #[command(flatten)]
+    pub metadata: TokenMetadataArgs,
+    /// Trading start, 0 to open trading right away
+    #[arg(long = "start-timestamp", default_value_t = 0)]
+    pub start_timestamp: u64,
+    #[arg(long = "profile")]
+    pub launch_profile: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetRouterArgs {
+    /// The master address, or the address of one of its bondings
+    pub address: String,
+    pub router_address: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Args)]
+pub struct UpgradeTokenArgs {
+    pub first_token_id: String,
+    pub second_token_id: String,
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use std::io::Read;
+
+/// Config file
+const CONFIG_FILE: &str = "config.toml";
+
+/// Master Interact configuration
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    gateway: String,
+    #[serde(default)]
+    pem: String,
+    #[serde(default)]
+    trace: bool,
+}
+
+impl Config {
+    // Deserializes config from file
+    pub fn load_config() -> Self {
+        let mut file = std::fs::File::open(CONFIG_FILE).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        toml::from_str(&content).unwrap()
+    }
+
+    // Returns the gateway
+    pub fn gateway(&self) -> &str {
+        &self.gateway
+    }
+
+    // Returns the pem file of the signing wallet, if any
+    pub fn pem(&self) -> Option<&str> {
+        if self.pem.is_empty() {
+            None
+        } else {
+            Some(&self.pem)
+        }
+    }
+
+    // Returns whether the steps should be recorded into a scenario
+    pub fn trace(&self) -> bool {
+        self.trace
+    }
+}
@@ -0,0 +1,156 @@
+mod master_interact_calls;
+mod master_interact_cli;
+mod master_interact_config;
+mod master_interact_output;
+mod master_interact_state;
+
+use clap::Parser;
+use master::config::ProxyTrait as _;
+use master_interact_calls::{deploy_step, master_step, upgrade_step, MasterStep};
+use master_interact_cli::{DeployArgs, InteractCliCommand, MasterCommand};
+use master_interact_config::Config;
+use master_interact_state::State;
+use multiversx_sc_snippets::{
+    env_logger,
+    erdrs::wallet::Wallet,
+    multiversx_sc::types::Address,
+    multiversx_sc_scenario::{
+        bech32,
+        scenario_format::interpret_trait::{InterpretableFrom, InterpreterContext},
+        scenario_model::*,
+    },
+    test_wallets, tokio, Interactor,
+};
+
+const INTERACTOR_SCENARIO_TRACE_PATH: &str = "master_interact_trace.scen.json";
+
+const MASTER_CODE_PATH: &str = "mxsc:../output/master.mxsc.json";
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let cli = master_interact_cli::InteractCli::parse();
+    let Some(command) = &cli.command else {
+        return;
+    };
+
+    let mut master_interact = MasterInteract::init().await;
+    match command {
+        InteractCliCommand::Deploy(args) => master_interact.deploy(args, cli.gas_limit).await,
+        InteractCliCommand::Upgrade => master_interact.upgrade(cli.gas_limit).await,
+        InteractCliCommand::Master(command) => master_interact.run(command, cli.gas_limit).await,
+    }
+}
+
+struct MasterInteract {
+    interactor: Interactor,
+    wallet_address: Address,
+    master_code: BytesValue,
+    state: State,
+}
+
+impl MasterInteract {
+    async fn init() -> Self {
+        let config = Config::load_config();
+        let mut interactor = Interactor::new(config.gateway()).await;
+        if config.trace() {
+            interactor = interactor.with_tracer(INTERACTOR_SCENARIO_TRACE_PATH).await;
+        }
+
+        let wallet = match config.pem() {
+            Some(pem) => Wallet::from_pem_file(pem).expect("could not load the pem file"),
+            None => test_wallets::alice(),
+        };
+        let wallet_address = interactor.register_wallet(wallet);
+        let master_code =
+            BytesValue::interpret_from(MASTER_CODE_PATH, &InterpreterContext::default());
+
+        MasterInteract {
+            interactor,
+            wallet_address,
+            master_code,
+            state: State::load_state(),
+        }
+    }
+
+    async fn deploy(&mut self, args: &DeployArgs, gas_limit: Option<u64>) {
+        let mut step = deploy_step(&mut self.state.default_master(), args)
+            .from(&self.wallet_address)
+            .code(&self.master_code);
+        if let Some(gas_limit) = gas_limit {
+            step = step.gas_limit(gas_limit);
+        }
+        self.interactor.sc_deploy(&mut step).await;
+
+        let response = step.response();
+        if !response.tx_error.is_success() {
+            println!("deploy failed: {}", response.tx_error.message);
+            return;
+        }
+        let new_address = response
+            .new_deployed_address
+            .as_ref()
+            .expect("the deploy returned no address");
+        let new_address_bech32 = bech32::encode(new_address);
+        self.state.set_master_address(&new_address_bech32);
+        println!("new master address: {new_address_bech32}");
+    }
+
+    /// The upgraded master stays paused until the migrations have run and it is resumed.
+    async fn upgrade(&mut self, gas_limit: Option<u64>) {
+        let mut step =
+            upgrade_step(&self.state.master(), &self.master_code).from(&self.wallet_address);
+        if let Some(gas_limit) = gas_limit {
+            step = step.gas_limit(gas_limit);
+        }
+        self.interactor.sc_call(&mut step).await;
+
+        let response = step.response();
+        if !response.tx_error.is_success() {
+            println!("upgrade failed: {}", response.tx_error.message);
+            return;
+        }
+        println!("master upgraded, run the migrations before resuming it");
+    }
+
+    async fn run(&mut self, command: &MasterCommand, gas_limit: Option<u64>) {
+        // a launch needs the gas of the schedule the master currently uses
+        let gas_limit = match (gas_limit, command) {
+            (None, MasterCommand::NewToken(_)) => Some(
+                self.interactor
+                    .quick_query(self.state.master().get_new_token_gas_limit())
+                    .await,
+            ),
+            _ => gas_limit,
+        };
+
+        match master_step(&mut self.state.master(), command) {
+            MasterStep::Call(step, printer) => {
+                let mut step = step.from(&self.wallet_address);
+                if let Some(gas_limit) = gas_limit {
+                    step = step.gas_limit(gas_limit);
+                }
+                self.interactor.sc_call(&mut step).await;
+
+                let response = step.response();
+                if !response.tx_error.is_success() {
+                    println!("call failed: {}", response.tx_error.message);
+                    return;
+                }
+                print_lines(printer(&response.out));
+                println!("successfully performed the call");
+            },
+            MasterStep::Query(mut step, printer) => {
+                self.interactor.sc_query(&mut step).await;
+                print_lines(printer(&step.response().out));
+            },
+        }
+    }
+}
+
+fn print_lines(lines: Vec<String>) {
+    for line in lines {
+        println!("{line}");
+    }
+}
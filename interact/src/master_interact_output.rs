@@ -0,0 +1,373 @@
+use master::{
+    airdrop::Airdrop,
+    allocation::SupplyAllocation,
+    anti_snipe::AntiSnipeConfig,
+    bonding_overrides::{BondingFilter, BondingParam, BondingParamPush},
+    bonding_proxy::State,
+    config::{FactoryConfig, GasSchedule},
+    creator_fees::CreatorFeesHistory,
+    curve::{CurveKind, CurveParams},
+    factory::{BondingParams, PairContractData, PairContractMetadata},
+    graduation::{GraduationPolicy, LpLock, LpPolicy},
+    metadata::TokenMetadata,
+    migration::MigrationCursor,
+    ranking::RankingEntry,
+    rate_limit::LaunchQuota,
+    schedule::UpcomingLaunch,
+    stats::PlatformStats,
+    treasury::{FeeAccount, FeeSource},
+    vesting::{CreatorBuyVesting, VestingAmounts, VestingSchedule},
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    bech32,
+    multiversx_sc::{
+        codec::TopDecode,
+        types::{
+            BigUint, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtTokenPayment,
+            ManagedAddress, ManagedBuffer, ManagedByteArray, TokenIdentifier,
+        },
+    },
+    num_bigint,
+};
+
+/// Turns the raw results of a call or query into the lines the interactor prints.
+pub type Printer = fn(&[Vec<u8>]) -> Vec<String>;
+
+/// A value printed on a single line.
+pub trait ShowValue {
+    fn show(&self) -> String;
+}
+
+/// A returned value, printed one struct field per line.
+pub trait ShowLines {
+    fn lines(&self) -> Vec<String>;
+}
+
+/// For endpoints that return nothing.
+pub fn nothing(_raw: &[Vec<u8>]) -> Vec<String> {
+    Vec::new()
+}
+
+pub fn single<T: TopDecode + ShowLines>(raw: &[Vec<u8>]) -> Vec<String> {
+    let bytes = raw.first().map(Vec::as_slice).unwrap_or_default();
+    decode::<T>(bytes).lines()
+}
+
+/// For `OptionalValue` results, which return no value at all when empty.
+pub fn optional<T: TopDecode + ShowLines>(raw: &[Vec<u8>]) -> Vec<String> {
+    match raw.first() {
+        Some(bytes) => decode::<T>(bytes).lines(),
+        None => vec![String::from("none")],
+    }
+}
+
+/// For `MultiValueEncoded` results, one line per item.
+pub fn variadic<T: TopDecode + ShowValue>(raw: &[Vec<u8>]) -> Vec<String> {
+    raw.iter().map(|bytes| decode::<T>(bytes).show()).collect()
+}
+
+fn decode<T: TopDecode>(bytes: &[u8]) -> T {
+    T::top_decode(bytes).expect("could not decode the result")
+}
+
+macro_rules! show_scalars {
+    ($($scalar:ty),*) => {
+        $(
+            impl ShowLines for $scalar {
+                fn lines(&self) -> Vec<String> {
+                    vec![self.show()]
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! show_struct {
+    ($name:ty { $($field:ident),* $(,)? }) => {
+        impl ShowLines for $name {
+            fn lines(&self) -> Vec<String> {
+                vec![$(format!("{}: {}", stringify!($field), self.$field.show())),*]
+            }
+        }
+
+        impl ShowValue for $name {
+            fn show(&self) -> String {
+                format!("{{{}}}", self.lines().join(", "))
+            }
+        }
+    };
+}
+
+impl ShowValue for bool {
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ShowValue for u32 {
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ShowValue for u64 {
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ShowValue for usize {
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ShowValue for BigUint<StaticApi> {
+    fn show(&self) -> String {
+        num_bigint::BigUint::from_bytes_be(self.to_bytes_be().as_slice()).to_string()
+    }
+}
+
+impl ShowValue for ManagedAddress<StaticApi> {
+    fn show(&self) -> String {
+        bech32::encode(&self.to_address())
+    }
+}
+
+impl ShowValue for ManagedBuffer<StaticApi> {
+    fn show(&self) -> String {
+        String::from_utf8_lossy(self.to_boxed_bytes().as_slice()).into_owned()
+    }
+}
+
+impl ShowValue for ManagedByteArray<StaticApi, 32> {
+    fn show(&self) -> String {
+        hex::encode(self.to_byte_array())
+    }
+}
+
+impl ShowValue for TokenIdentifier<StaticApi> {
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ShowValue for EgldOrEsdtTokenIdentifier<StaticApi> {
+    fn show(&self) -> String {
+        self.clone().into_name().show()
+    }
+}
+
+impl<T: ShowValue> ShowValue for Option<T> {
+    fn show(&self) -> String {
+        match self {
+            Some(value) => value.show(),
+            None => String::from("none"),
+        }
+    }
+}
+
+impl ShowValue for State {
+    fn show(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl ShowValue for LpPolicy {
+    fn show(&self) -> String {
+        match self {
+            LpPolicy::Burn => "burn",
+            LpPolicy::Lock => "lock",
+            LpPolicy::Treasury => "treasury",
+        }
+        .into()
+    }
+}
+
+impl ShowValue for CurveKind {
+    fn show(&self) -> String {
+        match self {
+            CurveKind::ConstantProduct => "constant-product",
+            CurveKind::Linear => "linear",
+            CurveKind::Exponential => "exponential",
+            CurveKind::Stepwise => "stepwise",
+        }
+        .into()
+    }
+}
+
+impl ShowValue for FeeSource {
+    fn show(&self) -> String {
+        match self {
+            FeeSource::LaunchFee => "launch-fee",
+            FeeSource::ScheduledLaunchFee => "scheduled-launch-fee",
+            FeeSource::CancelledLaunchFee => "cancelled-launch-fee",
+            FeeSource::SwapFees => "swap-fees",
+            FeeSource::GraduationLp => "graduation-lp",
+        }
+        .into()
+    }
+}
+
+impl ShowValue for BondingParam<StaticApi> {
+    fn show(&self) -> String {
+        match self {
+            BondingParam::FeesCollector(address) => format!("fees-collector {}", address.show()),
+            BondingParam::OracleAddress(address) => format!("oracle-address {}", address.show()),
+            BondingParam::MaxMarketCap(amount) => format!("max-market-cap {}", amount.show()),
+            BondingParam::ReachJeetdexFee(amount) => format!("reach-jeetdex-fee {}", amount.show()),
+            BondingParam::WegldUnwrapSc(address) => format!("wegld-unwrap-sc {}", address.show()),
+        }
+    }
+}
+
+impl ShowValue for BondingFilter {
+    fn show(&self) -> String {
+        match self {
+            BondingFilter::All => String::from("all"),
+            BondingFilter::LaunchProfile(profile_id) => format!("launch-profile {profile_id}"),
+            BondingFilter::NotGraduated => String::from("not-graduated"),
+        }
+    }
+}
+
+show_scalars!(
+    bool,
+    u32,
+    u64,
+    usize,
+    BigUint<StaticApi>,
+    ManagedAddress<StaticApi>,
+    TokenIdentifier<StaticApi>,
+    EgldOrEsdtTokenIdentifier<StaticApi>
+);
+
+show_struct!(GasSchedule {
+    new_token,
+    create_bonding,
+    register_router,
+    set_token_identifier,
+    callback,
+});
+show_struct!(FactoryConfig<StaticApi> {
+    pair_template_address,
+    token_supply,
+    fees_collector,
+    new_token_fee,
+    initial_virtual_liquidity,
+    allowed_token,
+    oracle_address,
+    max_market_cap,
+    jeetdex_router_sc_address,
+    issue_token_cost,
+    wegld_unwrap_sc,
+    reach_jeetdex_fee,
+    gas_schedule,
+});
+show_struct!(PairContractMetadata<StaticApi> { first_token_id, second_token_id, address });
+show_struct!(PairContractData<StaticApi> {
+    sc_address,
+    first_token_id,
+    second_token_id,
+    first_token_reserve,
+    second_token_reserve,
+    owner_fee_percent,
+    market_cap,
+    db_id,
+    state,
+});
+show_struct!(PlatformStats<StaticApi> {
+    total_launches,
+    active_bondings,
+    paused_bondings,
+    graduated_bondings,
+    total_launch_fees,
+    total_fees_forwarded,
+    total_refunds,
+    total_volume,
+});
+show_struct!(EgldOrEsdtTokenPayment<StaticApi> { token_identifier, token_nonce, amount });
+show_struct!(EsdtTokenPayment<StaticApi> { token_identifier, token_nonce, amount });
+show_struct!(CreatorFeesHistory<StaticApi> { token_id, total_earned, total_claimed });
+show_struct!(GraduationPolicy {
+    lp_policy,
+    lock_epochs
+});
+show_struct!(LpLock<StaticApi> { token_id, amount, unlock_epoch });
+show_struct!(TokenMetadata<StaticApi> {
+    description,
+    image_uri,
+    image_hash,
+    website,
+    twitter,
+    telegram,
+});
+show_struct!(MigrationCursor<StaticApi> { last_address });
+show_struct!(AntiSnipeConfig<StaticApi> { window_blocks, max_buy_per_address, fee_percent });
+show_struct!(RankingEntry<StaticApi> {
+    sc_address,
+    token_id,
+    market_cap,
+    market_cap_progress,
+    volume_24h,
+});
+show_struct!(LaunchQuota {
+    is_exempt,
+    max_launches_per_window,
+    remaining_in_window,
+    window_end_round,
+    max_launches_per_block,
+    remaining_in_block,
+});
+show_struct!(UpcomingLaunch<StaticApi> { sc_address, token_id, creator, start_timestamp });
+show_struct!(VestingSchedule<StaticApi> {
+    total_amount,
+    claimed_amount,
+    start_timestamp,
+    cliff_duration,
+    vesting_duration,
+});
+show_struct!(VestingAmounts<StaticApi> { locked, claimable, claimed });
+show_struct!(CreatorBuyVesting {
+    cliff_duration,
+    vesting_duration
+});
+show_struct!(SupplyAllocation<StaticApi> {
+    dex_reserve_percent,
+    airdrop_percent,
+    treasury_percent,
+    treasury_address,
+    dev_percent,
+    dev_cliff_duration,
+    dev_vesting_duration,
+});
+show_struct!(Airdrop<StaticApi> {
+    token_id,
+    creator,
+    merkle_root,
+    total_amount,
+    claimed_amount,
+    expiry_timestamp,
+    reclaimed,
+});
+show_struct!(CurveParams<StaticApi> {
+    kind,
+    initial_price,
+    price_increment,
+    growth_percent,
+    step_size,
+});
+show_struct!(BondingParams<StaticApi> {
+    allowed_token,
+    fees_collector,
+    initial_virtual_liquidity,
+    oracle_address,
+    max_market_cap,
+    jeetdex_router_sc_address,
+    issue_token_cost,
+    wegld_unwrap_sc,
+    reach_jeetdex_fee,
+});
+show_struct!(BondingParamPush<StaticApi> { param, filter, next_index });
+show_struct!(FeeAccount<StaticApi> { token_id, accrued, withdrawn, withdrawable });
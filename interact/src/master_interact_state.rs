@@ -0,0 +1,57 @@
+use crate::master_interact_calls::MasterContract;
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{Read, Write},
+    path::Path,
+};
+
+/// State file
+const STATE_FILE: &str = "state.toml";
+
+/// Master Interact state
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct State {
+    master_address: Option<String>,
+}
+
+impl State {
+    // Deserializes state from file
+    pub fn load_state() -> Self {
+        if Path::new(STATE_FILE).exists() {
+            let mut file = std::fs::File::open(STATE_FILE).unwrap();
+            let mut content = String::new();
+            file.read_to_string(&mut content).unwrap();
+            toml::from_str(&content).unwrap()
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Sets the master address
+    pub fn set_master_address(&mut self, address: &str) {
+        self.master_address = Some(String::from(address));
+    }
+
+    /// Returns the master contract
+    pub fn master(&self) -> MasterContract {
+        MasterContract::new(
+            self.master_address
+                .clone()
+                .expect("no known master contract, deploy first"),
+        )
+    }
+
+    /// Returns the master contract with default address, used for deploy calls
+    pub fn default_master(&self) -> MasterContract {
+        MasterContract::new("sc:master")
+    }
+}
+
+impl Drop for State {
+    // Serializes state to file
+    fn drop(&mut self) {
+        let mut file = std::fs::File::create(STATE_FILE).unwrap();
+        file.write_all(toml::to_string(self).unwrap().as_bytes())
+            .unwrap();
+    }
+}
@@ -0,0 +1,177 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::allocation;
+use crate::config;
+use crate::creator;
+use crate::factory;
+use crate::profile;
+use crate::stats;
+use crate::treasury;
+use crate::vesting;
+
+pub type Hash<M> = ManagedByteArray<M, 32>;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct Airdrop<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub creator: ManagedAddress<M>,
+    pub merkle_root: Hash<M>,
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub expiry_timestamp: u64,
+    pub reclaimed: bool,
+}
+
+#[multiversx_sc::module]
+pub trait AirdropModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + profile::LaunchProfileModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+    + vesting::VestingModule
+    + allocation::AllocationModule
+{
+    /// Creates an airdrop funded by the sent tokens plus the airdrop share reserved at launch.
+    /// Leaves of the Merkle tree are `sha256(address ++ amount)`, pairs are hashed sorted.
+    #[payable("*")]
+    #[endpoint(createAirdrop)]
+    fn create_airdrop(
+        &self,
+        token_id: TokenIdentifier,
+        merkle_root: Hash<Self::Api>,
+        expiry_timestamp: u64,
+    ) -> u64 {
+        self.require_token_creator(&token_id);
+        require!(
+            expiry_timestamp > self.blockchain().get_block_timestamp(),
+            "Expiry must be in the future"
+        );
+
+        require!(self.call_value().egld_value().clone_value() == 0, "Invalid airdrop token");
+        let mut total_amount = self.airdrop_reserve(&token_id).take();
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            require!(payment.token_identifier == token_id, "Invalid airdrop token");
+            self.add_owed_esdt(&token_id, &payment.amount);
+            total_amount += payment.amount;
+        }
+        require!(total_amount > 0, "Airdrop amount cannot be zero");
+
+        let airdrop_id = self.last_airdrop_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.airdrop(airdrop_id).set(Airdrop {
+            token_id: token_id.clone(),
+            creator: self.blockchain().get_caller(),
+            merkle_root,
+            total_amount,
+            claimed_amount: BigUint::zero(),
+            expiry_timestamp,
+            reclaimed: false,
+        });
+        self.token_airdrops(&token_id).insert(airdrop_id);
+
+        airdrop_id
+    }
+
+    #[endpoint(claimAirdrop)]
+    fn claim_airdrop(
+        &self,
+        airdrop_id: u64,
+        amount: BigUint,
+        proof: MultiValueEncoded<Hash<Self::Api>>,
+    ) {
+        let airdrop_mapper = self.airdrop(airdrop_id);
+        require!(!airdrop_mapper.is_empty(), "Airdrop does not exist");
+
+        let caller = self.blockchain().get_caller();
+        let mut airdrop = airdrop_mapper.get();
+        require!(
+            self.blockchain().get_block_timestamp() < airdrop.expiry_timestamp,
+            "Airdrop has expired"
+        );
+        require!(!self.airdrop_claimed(airdrop_id, &caller).get(), "Airdrop already claimed");
+        require!(
+            self.verify_merkle_proof(&airdrop.merkle_root, &caller, &amount, proof),
+            "Invalid Merkle proof"
+        );
+
+        airdrop.claimed_amount += &amount;
+        require!(
+            airdrop.claimed_amount <= airdrop.total_amount,
+            "Not enough tokens left in the airdrop"
+        );
+
+        self.airdrop_claimed(airdrop_id, &caller).set(true);
+        self.remove_owed_esdt(&airdrop.token_id, &amount);
+        self.send().direct_esdt(&caller, &airdrop.token_id, 0, &amount);
+        airdrop_mapper.set(airdrop);
+    }
+
+    /// Sends the unclaimed tokens of an expired airdrop back to its creator.
+    #[endpoint(reclaimAirdrop)]
+    fn reclaim_airdrop(&self, airdrop_id: u64) {
+        let airdrop_mapper = self.airdrop(airdrop_id);
+        require!(!airdrop_mapper.is_empty(), "Airdrop does not exist");
+
+        let mut airdrop = airdrop_mapper.get();
+        require!(
+            self.blockchain().get_block_timestamp() >= airdrop.expiry_timestamp,
+            "Airdrop has not expired yet"
+        );
+        require!(!airdrop.reclaimed, "Airdrop already reclaimed");
+
+        airdrop.reclaimed = true;
+        let unclaimed_amount = &airdrop.total_amount - &airdrop.claimed_amount;
+        if unclaimed_amount > 0 {
+            self.remove_owed_esdt(&airdrop.token_id, &unclaimed_amount);
+            self.send()
+                .direct_esdt(&airdrop.creator, &airdrop.token_id, 0, &unclaimed_amount);
+        }
+        airdrop_mapper.set(airdrop);
+    }
+
+    fn verify_merkle_proof(
+        &self,
+        merkle_root: &Hash<Self::Api>,
+        address: &ManagedAddress,
+        amount: &BigUint,
+        proof: MultiValueEncoded<Hash<Self::Api>>,
+    ) -> bool {
+        let mut leaf = address.as_managed_buffer().clone();
+        leaf.append(&amount.to_bytes_be_buffer());
+        let mut hash = self.crypto().sha256(&leaf);
+
+        for node in proof {
+            let mut pair = ManagedBuffer::new();
+            if hash.to_byte_array() <= node.to_byte_array() {
+                pair.append(hash.as_managed_buffer());
+                pair.append(node.as_managed_buffer());
+            } else {
+                pair.append(node.as_managed_buffer());
+                pair.append(hash.as_managed_buffer());
+            }
+            hash = self.crypto().sha256(&pair);
+        }
+
+        &hash == merkle_root
+    }
+
+    #[view(getAirdrop)]
+    #[storage_mapper("airdrop")]
+    fn airdrop(&self, airdrop_id: u64) -> SingleValueMapper<Airdrop<Self::Api>>;
+
+    #[view(isAirdropClaimed)]
+    #[storage_mapper("airdrop_claimed")]
+    fn airdrop_claimed(&self, airdrop_id: u64, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getTokenAirdrops)]
+    #[storage_mapper("token_airdrops")]
+    fn token_airdrops(&self, token_id: &TokenIdentifier) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("last_airdrop_id")]
+    fn last_airdrop_id(&self) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,174 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::creator;
+use crate::creator_fees::MAX_PERCENT;
+use crate::factory;
+use crate::profile;
+use crate::stats;
+use crate::treasury;
+use crate::vesting;
+
+/// Split of the issued supply, in basis points. Whatever is not allocated elsewhere goes
+/// to the bonding curve.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct SupplyAllocation<M: ManagedTypeApi> {
+    pub dex_reserve_percent: u64,
+    pub airdrop_percent: u64,
+    pub treasury_percent: u64,
+    pub treasury_address: ManagedAddress<M>,
+    pub dev_percent: u64,
+    pub dev_cliff_duration: u64,
+    pub dev_vesting_duration: u64,
+}
+
+impl<M: ManagedTypeApi> SupplyAllocation<M> {
+    /// What the curve gets out of `issued_supply`, i.e. the rounding of the other
+    /// allocations included.
+    pub fn curve_amount(&self, issued_supply: &BigUint<M>) -> BigUint<M> {
+        let mut curve_amount = issued_supply.clone();
+        for percent in [
+            self.dex_reserve_percent,
+            self.airdrop_percent,
+            self.treasury_percent,
+            self.dev_percent,
+        ] {
+            curve_amount -= issued_supply * percent / MAX_PERCENT;
+        }
+        curve_amount
+    }
+}
+
+#[multiversx_sc::module]
+pub trait AllocationModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + profile::LaunchProfileModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+    + vesting::VestingModule
+{
+    #[only_owner]
+    #[endpoint(setProfileSupplyAllocation)]
+    fn set_profile_supply_allocation(&self, profile_id: u32, allocation: SupplyAllocation<Self::Api>) {
+        self.require_launch_profile(profile_id);
+        let percents = [
+            allocation.dex_reserve_percent,
+            allocation.airdrop_percent,
+            allocation.treasury_percent,
+            allocation.dev_percent,
+        ];
+        let mut total_percent = 0u64;
+        for percent in percents {
+            require!(percent <= MAX_PERCENT, "Supply allocation cannot exceed 100%");
+            total_percent = match total_percent.checked_add(percent) {
+                Some(total) => total,
+                None => sc_panic!("Supply allocation cannot exceed 100%"),
+            };
+        }
+        require!(total_percent <= MAX_PERCENT, "Supply allocation cannot exceed 100%");
+        require!(total_percent < MAX_PERCENT, "Curve allocation cannot be zero");
+        require!(
+            allocation.treasury_percent == 0 || !allocation.treasury_address.is_zero(),
+            "Treasury address cannot be zero"
+        );
+        if allocation.dev_percent > 0 {
+            self.validate_lock_durations(allocation.dev_cliff_duration, allocation.dev_vesting_duration);
+        }
+
+        self.profile_supply_allocation(profile_id).set(allocation);
+    }
+
+    #[only_owner]
+    #[endpoint(clearProfileSupplyAllocation)]
+    fn clear_profile_supply_allocation(&self, profile_id: u32) {
+        self.profile_supply_allocation(profile_id).clear();
+    }
+
+    /// Share of `issued_supply` a launch with the profile puts on the curve.
+    fn get_curve_supply(&self, profile_id: u32, issued_supply: &BigUint) -> BigUint {
+        let allocation_mapper = self.profile_supply_allocation(profile_id);
+        if allocation_mapper.is_empty() {
+            return issued_supply.clone();
+        }
+
+        allocation_mapper.get().curve_amount(issued_supply)
+    }
+
+    /// Routes the non-curve allocations of a new token and returns the amount left for the curve.
+    fn split_issued_supply(
+        &self,
+        token_id: &TokenIdentifier,
+        profile_id: u32,
+        issued_supply: &BigUint,
+        vesting_start: u64,
+    ) -> BigUint {
+        let allocation_mapper = self.profile_supply_allocation(profile_id);
+        if allocation_mapper.is_empty() {
+            return issued_supply.clone();
+        }
+
+        let allocation = allocation_mapper.get();
+
+        let dex_reserve_amount = issued_supply * allocation.dex_reserve_percent / MAX_PERCENT;
+        if dex_reserve_amount > 0 {
+            self.dex_liquidity_reserve(token_id).set(&dex_reserve_amount);
+            self.add_owed_esdt(token_id, &dex_reserve_amount);
+        }
+
+        let airdrop_amount = issued_supply * allocation.airdrop_percent / MAX_PERCENT;
+        if airdrop_amount > 0 {
+            self.airdrop_reserve(token_id).set(&airdrop_amount);
+            self.add_owed_esdt(token_id, &airdrop_amount);
+        }
+
+        let treasury_amount = issued_supply * allocation.treasury_percent / MAX_PERCENT;
+        if treasury_amount > 0 {
+            self.send()
+                .direct_esdt(&allocation.treasury_address, token_id, 0, &treasury_amount);
+        }
+
+        let dev_amount = issued_supply * allocation.dev_percent / MAX_PERCENT;
+        if dev_amount > 0 {
+            self.add_vesting_schedule(
+                token_id,
+                &dev_amount,
+                vesting_start,
+                allocation.dev_cliff_duration,
+                allocation.dev_vesting_duration,
+            );
+        }
+
+        allocation.curve_amount(issued_supply)
+    }
+
+    /// Called by a bonding when it migrates to JeetDex, to receive the supply reserved
+    /// for the DEX pool.
+    #[endpoint(claimDexReserve)]
+    fn claim_dex_reserve(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        self.check_is_pair_sc(&caller);
+
+        let token_id = self.address_pair_map().get(&caller).unwrap().first_token_id;
+        let amount = self.dex_liquidity_reserve(&token_id).take();
+        if amount > 0 {
+            self.remove_owed_esdt(&token_id, &amount);
+            self.send().direct_esdt(&caller, &token_id, 0, &amount);
+        }
+        amount
+    }
+
+    #[view(getProfileSupplyAllocation)]
+    #[storage_mapper("profile_supply_allocation")]
+    fn profile_supply_allocation(&self, profile_id: u32) -> SingleValueMapper<SupplyAllocation<Self::Api>>;
+
+    #[view(getDexLiquidityReserve)]
+    #[storage_mapper("dex_liquidity_reserve")]
+    fn dex_liquidity_reserve(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getAirdropReserve)]
+    #[storage_mapper("airdrop_reserve")]
+    fn airdrop_reserve(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+}
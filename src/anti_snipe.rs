@@ -0,0 +1,66 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::creator_fees::MAX_PERCENT;
+use crate::factory;
+use crate::profile;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct AntiSnipeConfig<M: ManagedTypeApi> {
+    pub window_blocks: u64,
+    pub max_buy_per_address: BigUint<M>,
+    pub fee_percent: u64,
+}
+
+#[multiversx_sc::module]
+pub trait AntiSnipeModule:
+    config::ConfigModule + factory::FactoryModule + profile::LaunchProfileModule
+{
+    /// During the first `window_blocks` blocks of trading, each address can buy at most
+    /// `max_buy_per_address` tokens and buys pay `fee_percent` (basis points) instead of the normal fee.
+    #[only_owner]
+    #[endpoint(setProfileAntiSnipe)]
+    fn set_profile_anti_snipe(
+        &self,
+        profile_id: u32,
+        window_blocks: u64,
+        max_buy_per_address: BigUint,
+        fee_percent: u64,
+    ) {
+        self.require_launch_profile(profile_id);
+        require!(window_blocks > 0, "Anti-snipe window cannot be zero");
+        require!(max_buy_per_address > 0, "Max buy per address cannot be zero");
+        require!(fee_percent <= MAX_PERCENT, "Anti-snipe fee too high");
+
+        self.profile_anti_snipe(profile_id).set(AntiSnipeConfig {
+            window_blocks,
+            max_buy_per_address,
+            fee_percent,
+        });
+    }
+
+    #[only_owner]
+    #[endpoint(clearProfileAntiSnipe)]
+    fn clear_profile_anti_snipe(&self, profile_id: u32) {
+        self.profile_anti_snipe(profile_id).clear();
+    }
+
+    /// The anti-snipe window a bonding launched with the profile is initialized with.
+    fn get_profile_anti_snipe(&self, profile_id: u32) -> Option<AntiSnipeConfig<Self::Api>> {
+        let profile_mapper = self.profile_anti_snipe(profile_id);
+        if profile_mapper.is_empty() {
+            return None;
+        }
+
+        Some(profile_mapper.get())
+    }
+
+    #[view(getProfileAntiSnipe)]
+    #[storage_mapper("profile_anti_snipe")]
+    fn profile_anti_snipe(&self, profile_id: u32) -> SingleValueMapper<AntiSnipeConfig<Self::Api>>;
+
+    #[view(getBondingAntiSnipe)]
+    #[storage_mapper("bonding_anti_snipe")]
+    fn bonding_anti_snipe(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<AntiSnipeConfig<Self::Api>>;
+}
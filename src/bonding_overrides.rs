@@ -0,0 +1,226 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::bonding_launch_proxy::ProxyTrait as _;
+use crate::config;
+use crate::creator;
+use crate::factory::{self, BondingParams};
+use crate::profile;
+use crate::stats;
+use crate::treasury;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub enum BondingParam<M: ManagedTypeApi> {
+    FeesCollector(ManagedAddress<M>),
+    OracleAddress(ManagedAddress<M>),
+    MaxMarketCap(BigUint<M>),
+    ReachJeetdexFee(BigUint<M>),
+    WegldUnwrapSc(ManagedAddress<M>),
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq)]
+pub enum BondingFilter {
+    All,
+    LaunchProfile(u32),
+    NotGraduated,
+}
+
+impl<M: ManagedTypeApi> BondingParam<M> {
+    pub fn apply_to(&self, params: &mut BondingParams<M>) {
+        match self {
+            BondingParam::FeesCollector(address) => params.fees_collector = address.clone(),
+            BondingParam::OracleAddress(address) => params.oracle_address = address.clone(),
+            BondingParam::MaxMarketCap(amount) => params.max_market_cap = amount.clone(),
+            BondingParam::ReachJeetdexFee(amount) => params.reach_jeetdex_fee = amount.clone(),
+            BondingParam::WegldUnwrapSc(address) => params.wegld_unwrap_sc = address.clone(),
+        }
+    }
+}
+
+/// A push to every bonding matching `filter`, done in batches. `next_index` is the
+/// 1-based position in `all_bondings` of the next bonding to look at.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct BondingParamPush<M: ManagedTypeApi> {
+    pub param: BondingParam<M>,
+    pub filter: BondingFilter,
+    pub next_index: usize,
+}
+
+#[multiversx_sc::module]
+pub trait BondingOverridesModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + profile::LaunchProfileModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+{
+    #[only_owner]
+    #[endpoint(setBondingParam)]
+    fn set_bonding_param(&self, bonding_address: ManagedAddress, param: BondingParam<Self::Api>) {
+        self.check_is_pair_sc(&bonding_address);
+        self.validate_bonding_param(&param);
+        self.push_bonding_param(&bonding_address, &param);
+    }
+
+    #[only_owner]
+    #[endpoint(setBondingParamForBondings)]
+    fn set_bonding_param_for_bondings(
+        &self,
+        param: BondingParam<Self::Api>,
+        bonding_addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.validate_bonding_param(&param);
+        for bonding_address in bonding_addresses {
+            self.check_is_pair_sc(&bonding_address);
+            self.push_bonding_param(&bonding_address, &param);
+        }
+    }
+
+    /// Starts a push to every bonding matching the filter. Nothing is sent until
+    /// `continueBondingParamPush` is called.
+    #[only_owner]
+    #[endpoint(startBondingParamPush)]
+    fn start_bonding_param_push(&self, param: BondingParam<Self::Api>, filter: BondingFilter) {
+        require!(
+            self.bonding_param_push().is_empty(),
+            "A bonding param push is already in progress"
+        );
+        self.validate_bonding_param(&param);
+
+        self.bonding_param_push().set(BondingParamPush {
+            param,
+            filter,
+            next_index: 1,
+        });
+    }
+
+    /// Goes through the next `max_bondings` bondings of the pending push and returns how
+    /// many bondings are left. Bondings launched meanwhile are included.
+    #[only_owner]
+    #[endpoint(continueBondingParamPush)]
+    fn continue_bonding_param_push(&self, max_bondings: usize) -> usize {
+        require!(max_bondings > 0, "Batch size cannot be zero");
+        let push_mapper = self.bonding_param_push();
+        require!(!push_mapper.is_empty(), "No bonding param push in progress");
+
+        let mut push = push_mapper.get();
+        let all_bondings = self.all_bondings();
+        let total_bondings = all_bondings.len();
+        let mut scanned = 0;
+        while push.next_index <= total_bondings && scanned < max_bondings {
+            let bonding_address = all_bondings.get_by_index(push.next_index);
+            if self.matches_bonding_filter(&bonding_address, push.filter) {
+                self.push_bonding_param(&bonding_address, &push.param);
+            }
+
+            push.next_index += 1;
+            scanned += 1;
+        }
+
+        let remaining = total_bondings + 1 - push.next_index;
+        if remaining == 0 {
+            push_mapper.clear();
+        } else {
+            push_mapper.set(push);
+        }
+        remaining
+    }
+
+    #[only_owner]
+    #[endpoint(cancelBondingParamPush)]
+    fn cancel_bonding_param_push(&self) {
+        require!(!self.bonding_param_push().is_empty(), "No bonding param push in progress");
+        self.bonding_param_push().clear();
+    }
+
+    /// Checks the value on its own, before it is pushed to any bonding.
+    fn validate_bonding_param(&self, param: &BondingParam<Self::Api>) {
+        match param {
+            BondingParam::FeesCollector(address) => {
+                require!(!address.is_zero(), "Fees collector cannot be zero address");
+            },
+            BondingParam::OracleAddress(address) => {
+                require!(!address.is_zero(), "oracle cannot be zero");
+                require!(
+                    self.blockchain().is_smart_contract(address),
+                    "oracle must be a smart contract"
+                );
+            },
+            BondingParam::MaxMarketCap(amount) => {
+                require!(*amount > 0, "max_market_cap cannot be zero");
+            },
+            BondingParam::ReachJeetdexFee(amount) => {
+                require!(*amount > 0, "reach_jeetdex_fee cannot be zero");
+            },
+            BondingParam::WegldUnwrapSc(address) => {
+                require!(!address.is_zero(), "wegld_unwrap_sc cannot be zero");
+                require!(
+                    self.blockchain().is_smart_contract(address),
+                    "wegld_unwrap_sc must be a smart contract"
+                );
+            },
+        }
+    }
+
+    /// The rules across the params of a bonding, against the values it runs with rather
+    /// than the factory config, which may have changed since it was deployed.
+    fn validate_bonding_params(&self, params: &BondingParams<Self::Api>) {
+        require!(
+            params.max_market_cap > params.initial_virtual_liquidity,
+            "max_market_cap must be above IVL"
+        );
+    }
+
+    fn matches_bonding_filter(&self, bonding_address: &ManagedAddress, filter: BondingFilter) -> bool {
+        match filter {
+            BondingFilter::All => true,
+            BondingFilter::LaunchProfile(profile_id) => {
+                self.bonding_launch_profile(bonding_address).get() == profile_id
+            },
+            BondingFilter::NotGraduated => !self.is_graduated(bonding_address),
+        }
+    }
+
+    fn push_bonding_param(&self, bonding_address: &ManagedAddress, param: &BondingParam<Self::Api>) {
+        let mut params = self.get_bonding_params(bonding_address.clone());
+        param.apply_to(&mut params);
+        self.validate_bonding_params(&params);
+
+        self.forward_bonding_param(bonding_address, param);
+        self.bonding_params(bonding_address).set(params);
+    }
+
+    fn forward_bonding_param(&self, bonding_address: &ManagedAddress, param: &BondingParam<Self::Api>) {
+        let mut proxy = self.bonding_launch_proxy(bonding_address.clone());
+        let _: IgnoreValue = match param {
+            BondingParam::FeesCollector(address) => proxy
+                .set_fees_collector(address.clone())
+                .execute_on_dest_context(),
+            BondingParam::OracleAddress(address) => proxy
+                .set_oracle_address(address.clone())
+                .execute_on_dest_context(),
+            BondingParam::MaxMarketCap(amount) => proxy
+                .set_max_market_cap(amount.clone())
+                .execute_on_dest_context(),
+            BondingParam::ReachJeetdexFee(amount) => proxy
+                .set_reach_jeetdex_fee(amount.clone())
+                .execute_on_dest_context(),
+            BondingParam::WegldUnwrapSc(address) => proxy
+                .set_wegld_unwrap_sc(address.clone())
+                .execute_on_dest_context(),
+        };
+    }
+
+    #[view(getBondingParamPush)]
+    fn get_bonding_param_push(&self) -> OptionalValue<BondingParamPush<Self::Api>> {
+        if self.bonding_param_push().is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.bonding_param_push().get())
+    }
+
+    #[storage_mapper("bonding_param_push")]
+    fn bonding_param_push(&self) -> SingleValueMapper<BondingParamPush<Self::Api>>;
+}
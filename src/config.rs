@@ -3,6 +3,99 @@ multiversx_sc::derive_imports!();
 
 use crate::factory::PairTokens;
 
+pub const DEFAULT_NEW_TOKEN_GAS: u64 = 20_000_000;
+/// Gas set aside for the system SC to issue the token. With it, the steps after `new_token`
+/// add up to the 150M `newToken` checked before the schedule existed.
+pub const ISSUE_TOKEN_GAS: u64 = 60_000_000;
+pub const DEFAULT_CREATE_BONDING_GAS: u64 = 40_000_000;
+pub const DEFAULT_REGISTER_ROUTER_GAS: u64 = 10_000_000;
+pub const DEFAULT_SET_TOKEN_IDENTIFIER_GAS: u64 = 20_000_000;
+pub const DEFAULT_CALLBACK_GAS: u64 = 20_000_000;
+
+/// erd1deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaqtv0gag, an address nobody
+/// holds the key of.
+pub const DEAD_ADDRESS: [u8; 32] = [
+    0x6e, 0x7a, 0xd6, 0xe7, 0xad, 0x6e, 0x7a, 0xd6, 0xe7, 0xad, 0x6e, 0x7a, 0xd6, 0xe7, 0xad, 0x6e,
+    0x7a, 0xd6, 0xe7, 0xad, 0x6e, 0x7a, 0xd6, 0xe7, 0xad, 0x6e, 0x7a, 0xd6, 0xe7, 0xad, 0x6e, 0x7a,
+];
+
+/// Protocol limit on the gas of a single transaction.
+pub const MAX_GAS_PER_TX: u64 = 600_000_000;
+
+/// Gas reserved for each step of a launch. `new_token` covers `newToken` itself up to the
+/// issue call, `callback` covers the rest of the callback (storage, launch settings).
+///
+/// The issue call forwards all the gas left, as any legacy async call does, and its
+/// callback runs with what the system SC gives back. So no step is applied as a gas limit:
+/// the schedule is only enforced by `newToken` checking, before the issue call, that the
+/// gas left covers `issue_and_callback_gas`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct GasSchedule {
+    pub new_token: u64,
+    pub create_bonding: u64,
+    pub register_router: u64,
+    pub set_token_identifier: u64,
+    pub callback: u64,
+}
+
+impl GasSchedule {
+    pub fn default_schedule() -> Self {
+        GasSchedule {
+            new_token: DEFAULT_NEW_TOKEN_GAS,
+            create_bonding: DEFAULT_CREATE_BONDING_GAS,
+            register_router: DEFAULT_REGISTER_ROUTER_GAS,
+            set_token_identifier: DEFAULT_SET_TOKEN_IDENTIFIER_GAS,
+            callback: DEFAULT_CALLBACK_GAS,
+        }
+    }
+
+    /// Gas the issue call and its callback need once `newToken` reaches the issue call,
+    /// capped at `MAX_GAS_PER_TX`.
+    pub fn issue_and_callback_gas(&self) -> u64 {
+        checked_gas_sum(&[
+            ISSUE_TOKEN_GAS,
+            self.create_bonding,
+            self.register_router,
+            self.set_token_identifier,
+            self.callback,
+        ])
+        .map_or(MAX_GAS_PER_TX, |gas| gas.min(MAX_GAS_PER_TX))
+    }
+
+    /// Gas of the whole launch, or `None` if the steps overflow a u64.
+    pub fn total_gas(&self) -> Option<u64> {
+        checked_gas_sum(&[
+            self.new_token,
+            ISSUE_TOKEN_GAS,
+            self.create_bonding,
+            self.register_router,
+            self.set_token_identifier,
+            self.callback,
+        ])
+    }
+}
+
+fn checked_gas_sum(steps: &[u64]) -> Option<u64> {
+    steps.iter().try_fold(0u64, |total, step| total.checked_add(*step))
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Debug)]
+pub struct FactoryConfig<M: ManagedTypeApi> {
+    pub pair_template_address: ManagedAddress<M>,
+    pub token_supply: BigUint<M>,
+    pub fees_collector: ManagedAddress<M>,
+    pub new_token_fee: BigUint<M>,
+    pub initial_virtual_liquidity: BigUint<M>,
+    pub allowed_token: TokenIdentifier<M>,
+    pub oracle_address: ManagedAddress<M>,
+    pub max_market_cap: BigUint<M>,
+    pub jeetdex_router_sc_address: ManagedAddress<M>,
+    pub issue_token_cost: BigUint<M>,
+    pub wegld_unwrap_sc: ManagedAddress<M>,
+    pub reach_jeetdex_fee: BigUint<M>,
+    pub gas_schedule: GasSchedule,
+}
+
 #[multiversx_sc::module]
 pub trait ConfigModule {
     fn is_active(&self) -> bool {
@@ -32,8 +125,9 @@ pub trait ConfigModule {
     #[only_owner]
     #[endpoint(setFeesCollector)]
     fn set_fees_collector(&self, fees_collector: ManagedAddress) {
-        require!(fees_collector != ManagedAddress::zero(), "Fees collector cannot be zero address");
-        self.fees_collector().set(&fees_collector);
+        let mut config = self.get_config();
+        config.fees_collector = fees_collector;
+        self.apply_config(&config);
     }
 
     #[storage_mapper("ivl")]
@@ -42,7 +136,9 @@ pub trait ConfigModule {
     #[only_owner]
     #[endpoint(setIVL)]
     fn set_initial_virtual_liquidity(&self, virtual_liquidity: BigUint) {
-        self.initial_virtual_liquidity().set(&virtual_liquidity);
+        let mut config = self.get_config();
+        config.initial_virtual_liquidity = virtual_liquidity;
+        self.apply_config(&config);
     }
 
     #[storage_mapper("issue_token_cost")]
@@ -59,8 +155,9 @@ pub trait ConfigModule {
     #[only_owner]
     #[endpoint(setTokenSupply)]
     fn set_token_supply(&self, token_supply: BigUint) {
-        require!(token_supply > 0, "Token Supply cannot be zero");
-        self.token_supply().set(&token_supply);
+        let mut config = self.get_config();
+        config.token_supply = token_supply;
+        self.apply_config(&config);
     }
 
     #[view(getNewTokenFee)]
@@ -70,8 +167,9 @@ pub trait ConfigModule {
     #[only_owner]
     #[endpoint(setNewTokenFee)]
     fn set_new_token_fee(&self, new_token_fee: BigUint) {
-        require!(new_token_fee > 0, "Token Fee cannot be zero");
-        self.new_token_fee().set(&new_token_fee);
+        let mut config = self.get_config();
+        config.new_token_fee = new_token_fee;
+        self.apply_config(&config);
     }
 
     // #[view(getDexTokenFee)]
@@ -96,8 +194,9 @@ pub trait ConfigModule {
     #[only_owner]
     #[endpoint(setMaxMarketCap)]
     fn set_max_market_cap(&self, max_market_cap: BigUint) {
-        require!(max_market_cap > 0, "max_market_cap cannot be zero");
-        self.max_market_cap().set(&max_market_cap);
+        let mut config = self.get_config();
+        config.max_market_cap = max_market_cap;
+        self.apply_config(&config);
     }
 
     #[view(getBondingTemplateAddress)]
@@ -106,8 +205,10 @@ pub trait ConfigModule {
 
     #[only_owner]
     #[endpoint(setBondingTemplateAddress)]
-    fn set_Bonding_template_address(&self, address: ManagedAddress) {
-        self.pair_template_address().set(&address);
+    fn set_bonding_template_address(&self, address: ManagedAddress) {
+        let mut config = self.get_config();
+        config.pair_template_address = address;
+        self.apply_config(&config);
     }
 
 
@@ -122,12 +223,232 @@ pub trait ConfigModule {
     #[storage_mapper("address_pair_map")]
     fn address_pair_map(&self) -> MapMapper<ManagedAddress, PairTokens<Self::Api>>;
 
+    #[only_owner]
+    #[endpoint(setGasSchedule)]
+    fn set_gas_schedule(&self, gas_schedule: GasSchedule) {
+        let mut config = self.get_config();
+        config.gas_schedule = gas_schedule;
+        self.apply_config(&config);
+    }
+
+    /// Deployments that never set a schedule use the defaults, with which `newToken`
+    /// needs the same 150M gas left at the issue call that it used to check.
+    #[view(getGasSchedule)]
+    fn get_gas_schedule(&self) -> GasSchedule {
+        let gas_schedule_mapper = self.gas_schedule();
+        if gas_schedule_mapper.is_empty() {
+            return GasSchedule::default_schedule();
+        }
+
+        gas_schedule_mapper.get()
+    }
+
+    /// Gas limit the frontend should set on a `newToken` transaction.
+    #[view(getNewTokenGasLimit)]
+    fn get_new_token_gas_limit(&self) -> u64 {
+        self.get_gas_schedule()
+            .total_gas()
+            .map_or(MAX_GAS_PER_TX, |gas| gas.min(MAX_GAS_PER_TX))
+    }
+
+    #[storage_mapper("gas_schedule")]
+    fn gas_schedule(&self) -> SingleValueMapper<GasSchedule>;
+
+    #[view(getConfig)]
+    fn get_config(&self) -> FactoryConfig<Self::Api> {
+        FactoryConfig {
+            pair_template_address: self.pair_template_address().get(),
+            token_supply: self.token_supply().get(),
+            fees_collector: self.fees_collector().get(),
+            new_token_fee: self.new_token_fee().get(),
+            initial_virtual_liquidity: self.initial_virtual_liquidity().get(),
+            allowed_token: self.allowed_token().get(),
+            oracle_address: self.oracle_address().get(),
+            max_market_cap: self.max_market_cap().get(),
+            jeetdex_router_sc_address: self.jeetdex_router_sc_address().get(),
+            issue_token_cost: self.issue_token_cost().get(),
+            wegld_unwrap_sc: self.wegld_unwrap_sc().get(),
+            reach_jeetdex_fee: self.reach_jeetdex_fee().get(),
+            gas_schedule: self.get_gas_schedule(),
+        }
+    }
+
+    /// Replaces the whole factory config at once. The allowed token keys every registered
+    /// pair, so it cannot be changed here.
+    #[only_owner]
+    #[endpoint(setConfig)]
+    fn set_config(&self, config: FactoryConfig<Self::Api>) {
+        require!(
+            config.allowed_token == self.allowed_token().get(),
+            "Allowed token cannot be changed"
+        );
+        self.apply_config(&config);
+    }
+
+    /// Every config change goes through here, so that the invariants are checked on the
+    /// whole config the change leaves behind and not only on the changed field.
+    fn apply_config(&self, config: &FactoryConfig<Self::Api>) {
+        self.validate_config(config);
+
+        self.pair_template_address().set(&config.pair_template_address);
+        self.token_supply().set(&config.token_supply);
+        self.fees_collector().set(&config.fees_collector);
+        self.new_token_fee().set(&config.new_token_fee);
+        self.initial_virtual_liquidity().set(&config.initial_virtual_liquidity);
+        self.oracle_address().set(&config.oracle_address);
+        self.max_market_cap().set(&config.max_market_cap);
+        self.jeetdex_router_sc_address().set(&config.jeetdex_router_sc_address);
+        self.issue_token_cost().set(&config.issue_token_cost);
+        self.wegld_unwrap_sc().set(&config.wegld_unwrap_sc);
+        self.reach_jeetdex_fee().set(&config.reach_jeetdex_fee);
+        self.gas_schedule().set(&config.gas_schedule);
+    }
+
+    fn validate_config(&self, config: &FactoryConfig<Self::Api>) {
+        require!(!config.pair_template_address.is_zero(), "template cannot be zero");
+        require!(
+            self.blockchain().is_smart_contract(&config.pair_template_address),
+            "template must be a smart contract"
+        );
+        require!(
+            !config.fees_collector.is_zero(),
+            "Fees collector cannot be zero address"
+        );
+        require!(!config.oracle_address.is_zero(), "oracle cannot be zero");
+        require!(
+            self.blockchain().is_smart_contract(&config.oracle_address),
+            "oracle must be a smart contract"
+        );
+        require!(
+            !config.jeetdex_router_sc_address.is_zero(),
+            "jeetdex_router_sc_address cannot be zero"
+        );
+        require!(
+            self.blockchain().is_smart_contract(&config.jeetdex_router_sc_address),
+            "jeetdex_router_sc_address must be a smart contract"
+        );
+        require!(!config.wegld_unwrap_sc.is_zero(), "wegld_unwrap_sc cannot be zero");
+        require!(
+            self.blockchain().is_smart_contract(&config.wegld_unwrap_sc),
+            "wegld_unwrap_sc must be a smart contract"
+        );
+
+        require!(config.token_supply > 0, "Token Supply cannot be zero");
+        require!(config.new_token_fee > 0, "Token Fee cannot be zero");
+        require!(config.issue_token_cost > 0, "issue_token_cost cannot be zero");
+        require!(
+            config.new_token_fee >= config.issue_token_cost,
+            "Token Fee must cover issue_token_cost"
+        );
+        require!(config.initial_virtual_liquidity > 0, "IVL cannot be zero");
+        require!(config.max_market_cap > 0, "max_market_cap cannot be zero");
+        require!(
+            config.max_market_cap > config.initial_virtual_liquidity,
+            "max_market_cap must be above IVL"
+        );
+        require!(config.reach_jeetdex_fee > 0, "reach_jeetdex_fee cannot be zero");
+
+        let gas_schedule = &config.gas_schedule;
+        require!(
+            gas_schedule.new_token > 0
+                && gas_schedule.create_bonding > 0
+                && gas_schedule.register_router > 0
+                && gas_schedule.set_token_identifier > 0
+                && gas_schedule.callback > 0,
+            "Gas schedule steps cannot be zero"
+        );
+        require!(
+            gas_schedule.total_gas().map_or(false, |gas| gas <= MAX_GAS_PER_TX),
+            "Gas schedule exceeds the max gas per transaction"
+        );
+    }
+
+    /// Every bonding deployed by the master, in launch order.
+    #[view(getAllBondings)]
+    #[storage_mapper("all_bondings")]
+    fn all_bondings(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getBondingCreator)]
+    #[storage_mapper("bonding_creator")]
+    fn bonding_creator(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
     #[storage_mapper("allowed_token")]
     fn allowed_token(&self) -> SingleValueMapper<TokenIdentifier>;
 
     #[storage_mapper("jeet_token_id")]
     fn jeet_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
+    fn check_creator_allowed(&self, creator: &ManagedAddress) {
+        require!(
+            !self.creator_blocklist().contains(creator),
+            "Address is blocked from launching tokens"
+        );
+        require!(
+            !self.allowlist_enabled().get() || self.creator_allowlist().contains(creator),
+            "Address is not allowed to launch tokens"
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(setAllowlistEnabled)]
+    fn set_allowlist_enabled(&self, enabled: bool) {
+        self.allowlist_enabled().set(enabled);
+    }
+
+    #[only_owner]
+    #[endpoint(addToAllowlist)]
+    fn add_to_allowlist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        for address in addresses {
+            self.creator_allowlist().insert(address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeFromAllowlist)]
+    fn remove_from_allowlist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        for address in addresses {
+            self.creator_allowlist().swap_remove(&address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(addToBlocklist)]
+    fn add_to_blocklist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        for address in addresses {
+            self.creator_blocklist().insert(address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeFromBlocklist)]
+    fn remove_from_blocklist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        for address in addresses {
+            self.creator_blocklist().swap_remove(&address);
+        }
+    }
+
+    #[view(isAllowlisted)]
+    fn is_allowlisted(&self, address: ManagedAddress) -> bool {
+        self.creator_allowlist().contains(&address)
+    }
+
+    #[view(isBlocklisted)]
+    fn is_blocklisted(&self, address: ManagedAddress) -> bool {
+        self.creator_blocklist().contains(&address)
+    }
+
+    #[view(isAllowlistEnabled)]
+    #[storage_mapper("allowlist_enabled")]
+    fn allowlist_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getAllowlist)]
+    #[storage_mapper("creator_allowlist")]
+    fn creator_allowlist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getBlocklist)]
+    #[storage_mapper("creator_blocklist")]
+    fn creator_blocklist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
     
 
 }
@@ -0,0 +1,81 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::factory;
+
+#[multiversx_sc::module]
+pub trait CreatorModule: config::ConfigModule + factory::FactoryModule {
+    #[endpoint(proposeCreatorTransfer)]
+    fn propose_creator_transfer(&self, token_id: TokenIdentifier, new_creator: ManagedAddress) {
+        let bonding_address = self.require_token_creator(&token_id);
+        require!(!new_creator.is_zero(), "New creator cannot be zero address");
+        require!(
+            new_creator != self.bonding_creator(&bonding_address).get(),
+            "Address is already the creator"
+        );
+
+        self.pending_creator(&bonding_address).set(&new_creator);
+    }
+
+    #[endpoint(cancelCreatorTransfer)]
+    fn cancel_creator_transfer(&self, token_id: TokenIdentifier) {
+        let bonding_address = self.require_token_creator(&token_id);
+        require!(
+            !self.pending_creator(&bonding_address).is_empty(),
+            "No pending creator transfer"
+        );
+
+        self.pending_creator(&bonding_address).clear();
+    }
+
+    #[endpoint(acceptCreatorTransfer)]
+    fn accept_creator_transfer(&self, token_id: TokenIdentifier) {
+        let bonding_address = self.require_launched_token(&token_id);
+        let pending_creator_mapper = self.pending_creator(&bonding_address);
+        require!(!pending_creator_mapper.is_empty(), "No pending creator transfer");
+        require!(
+            self.blockchain().get_caller() == pending_creator_mapper.get(),
+            "Only the proposed creator can accept the transfer"
+        );
+
+        self.bonding_creator(&bonding_address).set(pending_creator_mapper.get());
+        pending_creator_mapper.clear();
+    }
+
+    fn require_launched_token(&self, token_id: &TokenIdentifier) -> ManagedAddress {
+        let bonding_address = self.get_pair(token_id.clone(), self.allowed_token().get());
+        require!(!bonding_address.is_zero(), "Token was not launched by this factory");
+        bonding_address
+    }
+
+    /// Returns the bonding of `token_id` after checking that the caller is its current creator.
+    fn require_token_creator(&self, token_id: &TokenIdentifier) -> ManagedAddress {
+        let bonding_address = self.require_launched_token(token_id);
+        require!(
+            self.blockchain().get_caller() == self.bonding_creator(&bonding_address).get(),
+            "Only the token creator can perform this action"
+        );
+        bonding_address
+    }
+
+    #[view(getTokenCreator)]
+    fn get_token_creator(&self, token_id: TokenIdentifier) -> ManagedAddress {
+        let bonding_address = self.require_launched_token(&token_id);
+        self.bonding_creator(&bonding_address).get()
+    }
+
+    #[view(getPendingCreator)]
+    fn get_pending_creator(&self, token_id: TokenIdentifier) -> OptionalValue<ManagedAddress> {
+        let bonding_address = self.require_launched_token(&token_id);
+        let pending_creator_mapper = self.pending_creator(&bonding_address);
+        if pending_creator_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(pending_creator_mapper.get())
+    }
+
+    #[storage_mapper("pending_creator")]
+    fn pending_creator(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+}
@@ -0,0 +1,119 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::stats;
+use crate::treasury::{self, FeeSource};
+
+pub const MAX_PERCENT: u64 = 10_000;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct CreatorFeesHistory<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub total_earned: BigUint<M>,
+    pub total_claimed: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait CreatorFeesModule: config::ConfigModule + stats::StatsModule + treasury::TreasuryModule {
+    /// Share of the swap fees pushed by bondings that goes to the token creator, in basis points.
+    #[only_owner]
+    #[endpoint(setCreatorFeeShare)]
+    fn set_creator_fee_share(&self, creator_fee_share: u64) {
+        require!(creator_fee_share <= MAX_PERCENT, "Creator fee share too high");
+        self.creator_fee_share().set(creator_fee_share);
+    }
+
+    /// Called by a bonding with the swap fees it collected. The creator share is credited
+    /// to the current creator of the bonding, the rest is accrued as platform fees.
+    #[payable("*")]
+    #[endpoint(depositSwapFees)]
+    fn deposit_swap_fees(&self) {
+        let caller = self.blockchain().get_caller();
+        self.check_is_pair_sc(&caller);
+
+        let (token_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(amount > 0, "No fees sent");
+
+        let creator = self.bonding_creator(&caller).get();
+        let creator_amount = if creator.is_zero() {
+            BigUint::zero()
+        } else {
+            &amount * self.creator_fee_share().get() / MAX_PERCENT
+        };
+
+        if creator_amount > 0 {
+            self.creator_fee_tokens(&creator).insert(token_id.clone());
+            self.creator_claimable_fees(&creator, &token_id)
+                .update(|claimable| *claimable += &creator_amount);
+            self.creator_earned_fees(&creator, &token_id)
+                .update(|earned| *earned += &creator_amount);
+            self.add_owed(&token_id, &creator_amount);
+        }
+
+        let remaining_amount = amount - creator_amount;
+        self.accrue_fees(FeeSource::SwapFees, &token_id, &remaining_amount);
+    }
+
+    #[endpoint(claimCreatorFees)]
+    fn claim_creator_fees(&self) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        let mut payments = MultiValueEncoded::new();
+        for token_id in self.creator_fee_tokens(&caller).iter() {
+            let amount = self.creator_claimable_fees(&caller, &token_id).take();
+            if amount == 0 {
+                continue;
+            }
+
+            self.creator_claimed_fees(&caller, &token_id)
+                .update(|claimed| *claimed += &amount);
+            self.remove_owed(&token_id, &amount);
+            self.send().direct(&caller, &token_id, 0, &amount);
+            payments.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+        }
+
+        require!(!payments.is_empty(), "No fees to claim");
+        payments
+    }
+
+    #[view(getClaimableCreatorFees)]
+    fn get_claimable_creator_fees(&self, creator: ManagedAddress) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let mut payments = MultiValueEncoded::new();
+        for token_id in self.creator_fee_tokens(&creator).iter() {
+            let amount = self.creator_claimable_fees(&creator, &token_id).get();
+            if amount > 0 {
+                payments.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+            }
+        }
+        payments
+    }
+
+    #[view(getCreatorFeesHistory)]
+    fn get_creator_fees_history(&self, creator: ManagedAddress) -> MultiValueEncoded<CreatorFeesHistory<Self::Api>> {
+        let mut history = MultiValueEncoded::new();
+        for token_id in self.creator_fee_tokens(&creator).iter() {
+            history.push(CreatorFeesHistory {
+                total_earned: self.creator_earned_fees(&creator, &token_id).get(),
+                total_claimed: self.creator_claimed_fees(&creator, &token_id).get(),
+                token_id,
+            });
+        }
+        history
+    }
+
+    #[view(getCreatorFeeShare)]
+    #[storage_mapper("creator_fee_share")]
+    fn creator_fee_share(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("creator_fee_tokens")]
+    fn creator_fee_tokens(&self, creator: &ManagedAddress) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("creator_claimable_fees")]
+    fn creator_claimable_fees(&self, creator: &ManagedAddress, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("creator_earned_fees")]
+    fn creator_earned_fees(&self, creator: &ManagedAddress, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("creator_claimed_fees")]
+    fn creator_claimed_fees(&self, creator: &ManagedAddress, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,214 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::allocation;
+use crate::bonding_proxy::ProxyTrait as _;
+use crate::config;
+use crate::creator;
+use crate::creator_fees::MAX_PERCENT;
+use crate::factory::{self, PairData};
+use crate::profile;
+use crate::stats;
+use crate::treasury;
+use crate::vesting;
+
+const ONE_TOKEN: u64 = 1_000_000_000_000_000_000;
+const MAX_CURVE_STEPS: u64 = 1_000;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq)]
+pub enum CurveKind {
+    ConstantProduct,
+    Linear,
+    Exponential,
+    Stepwise,
+}
+
+/// Prices are expressed in the allowed token for one whole launched token.
+/// - `ConstantProduct`: virtual reserves seeded with the initial virtual liquidity, no parameters
+/// - `Linear`: `initial_price + price_increment * sold_tokens`
+/// - `Exponential`: `initial_price` growing by `growth_percent` (basis points) every `step_size`
+/// - `Stepwise`: `initial_price` growing by `price_increment` every `step_size`
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct CurveParams<M: ManagedTypeApi> {
+    pub kind: CurveKind,
+    pub initial_price: BigUint<M>,
+    pub price_increment: BigUint<M>,
+    pub growth_percent: u64,
+    pub step_size: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait CurveModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + profile::LaunchProfileModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+    + vesting::VestingModule
+    + allocation::AllocationModule
+{
+    #[only_owner]
+    #[endpoint(setProfileCurve)]
+    fn set_profile_curve(&self, profile_id: u32, params: CurveParams<Self::Api>) {
+        self.require_launch_profile(profile_id);
+        self.validate_curve_params(&params);
+        self.profile_curve(profile_id).set(params);
+    }
+
+    #[only_owner]
+    #[endpoint(clearProfileCurve)]
+    fn clear_profile_curve(&self, profile_id: u32) {
+        self.profile_curve(profile_id).clear();
+    }
+
+    fn validate_curve_params(&self, params: &CurveParams<Self::Api>) {
+        match params.kind {
+            CurveKind::ConstantProduct => {},
+            CurveKind::Linear => {
+                require!(params.initial_price > 0, "Initial price cannot be zero");
+            },
+            CurveKind::Exponential | CurveKind::Stepwise => {
+                require!(params.initial_price > 0, "Initial price cannot be zero");
+                require!(
+                    &params.step_size * MAX_CURVE_STEPS >= self.token_supply().get(),
+                    "Step size too small for the token supply"
+                );
+                require!(
+                    params.kind != CurveKind::Exponential || params.growth_percent > 0,
+                    "Growth percent cannot be zero"
+                );
+            },
+        }
+    }
+
+    /// The curve a bonding launched with the profile is initialized with. Bondings without
+    /// a curve use the default constant product curve.
+    fn get_profile_curve(&self, profile_id: u32) -> Option<CurveParams<Self::Api>> {
+        let profile_mapper = self.profile_curve(profile_id);
+        if profile_mapper.is_empty() {
+            return None;
+        }
+
+        Some(profile_mapper.get())
+    }
+
+    /// Cost, in the allowed token, of buying `amount` tokens from the bonding of `token_id` in its current state.
+    #[view(getBuyQuote)]
+    fn get_buy_quote(&self, token_id: TokenIdentifier, amount: BigUint) -> BigUint {
+        let bonding_address = self.get_pair(token_id, self.allowed_token().get());
+        require!(!bonding_address.is_zero(), "Bonding does not exists");
+
+        let pair_data: PairData<Self::Api> = self
+            .bonding_view_proxy(bonding_address.clone())
+            .get_pair_data()
+            .execute_on_dest_context_readonly();
+        self.buy_quote(
+            &bonding_address,
+            &pair_data.first_token_reserve,
+            &pair_data.second_token_reserve,
+            &amount,
+        )
+    }
+
+    /// The part of the quote that does not depend on reading the bonding.
+    fn buy_quote(
+        &self,
+        bonding_address: &ManagedAddress,
+        token_reserve: &BigUint,
+        quote_reserve: &BigUint,
+        amount: &BigUint,
+    ) -> BigUint {
+        require!(amount < token_reserve, "Not enough tokens on the curve");
+
+        let curve_mapper = self.bonding_curve(bonding_address);
+        if curve_mapper.is_empty() || curve_mapper.get().kind == CurveKind::ConstantProduct {
+            return self.constant_product_cost(quote_reserve, token_reserve, amount);
+        }
+
+        let sold = self.bonding_curve_supply(bonding_address).get() - token_reserve;
+        self.curve_cost(&curve_mapper.get(), &sold, amount)
+    }
+
+    /// Cost of buying `amount` tokens after `sold` tokens were already bought, on a fresh
+    /// launch using the curve and the supply allocation of `profile_id`.
+    #[view(simulateBuyCost)]
+    fn simulate_buy_cost(&self, profile_id: u32, sold: BigUint, amount: BigUint) -> BigUint {
+        self.require_launch_profile(profile_id);
+
+        let curve_supply = self.get_curve_supply(profile_id, &self.token_supply().get());
+        require!(&sold + &amount < curve_supply, "Not enough tokens on the curve");
+
+        let profile_mapper = self.profile_curve(profile_id);
+        if profile_mapper.is_empty() || profile_mapper.get().kind == CurveKind::ConstantProduct {
+            let initial_quote_reserve = self.initial_virtual_liquidity().get();
+            let k = &initial_quote_reserve * &curve_supply;
+            let token_reserve = curve_supply - sold;
+            let quote_reserve = &k / &token_reserve;
+            return self.constant_product_cost(&quote_reserve, &token_reserve, &amount);
+        }
+
+        self.curve_cost(&profile_mapper.get(), &sold, &amount)
+    }
+
+    fn constant_product_cost(&self, quote_reserve: &BigUint, token_reserve: &BigUint, amount: &BigUint) -> BigUint {
+        let k = quote_reserve * token_reserve;
+        let new_token_reserve = token_reserve - amount;
+        let new_quote_reserve = (&k + &new_token_reserve - 1u32) / &new_token_reserve;
+        new_quote_reserve - quote_reserve
+    }
+
+    fn curve_cost(&self, params: &CurveParams<Self::Api>, sold: &BigUint, amount: &BigUint) -> BigUint {
+        let one_token = BigUint::from(ONE_TOKEN);
+        match params.kind {
+            CurveKind::ConstantProduct => sc_panic!("Constant product curve has no closed form cost"),
+            CurveKind::Linear => {
+                let end = sold + amount;
+                let base_cost = &params.initial_price * amount / &one_token;
+                let slope_cost = &params.price_increment * &(&end * &end - sold * sold)
+                    / (&one_token * &one_token * 2u32);
+                base_cost + slope_cost
+            },
+            CurveKind::Exponential | CurveKind::Stepwise => {
+                let end = sold + amount;
+                // the supply may have been raised since the step size was checked against it
+                require!(
+                    end <= &params.step_size * MAX_CURVE_STEPS,
+                    "Step size too small for the token supply"
+                );
+                let mut cost = BigUint::zero();
+                let mut step_start = BigUint::zero();
+                let mut price = params.initial_price.clone();
+                while step_start < end {
+                    let step_end = &step_start + &params.step_size;
+                    if step_end > *sold {
+                        let from = if step_start > *sold { step_start.clone() } else { sold.clone() };
+                        let to = if step_end < end { step_end.clone() } else { end.clone() };
+                        cost += &price * &(to - from) / &one_token;
+                    }
+
+                    price = match params.kind {
+                        CurveKind::Exponential => {
+                            &price * (MAX_PERCENT + params.growth_percent) / MAX_PERCENT
+                        },
+                        _ => &price + &params.price_increment,
+                    };
+                    step_start = step_end;
+                }
+                cost
+            },
+        }
+    }
+
+    #[view(getProfileCurve)]
+    #[storage_mapper("profile_curve")]
+    fn profile_curve(&self, profile_id: u32) -> SingleValueMapper<CurveParams<Self::Api>>;
+
+    #[view(getBondingCurve)]
+    #[storage_mapper("bonding_curve")]
+    fn bonding_curve(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<CurveParams<Self::Api>>;
+
+    #[view(getBondingCurveSupply)]
+    #[storage_mapper("bonding_curve_supply")]
+    fn bonding_curve_supply(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
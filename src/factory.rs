@@ -1,10 +1,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::anti_snipe::AntiSnipeConfig;
+use crate::bonding_proxy::{self, ProxyTrait as _, State};
 use crate::config;
-use bonding::ProxyTrait as _;
-use bonding::config::ProxyTrait as _;
-use bonding::contexts::base::State;
+use crate::curve::CurveParams;
 
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi)]
 pub struct PairTokens<M: ManagedTypeApi> {
@@ -12,11 +12,53 @@ pub struct PairTokens<M: ManagedTypeApi> {
     pub second_token_id: TokenIdentifier<M>,
 }
 
+/// Launch settings a bonding is initialized with, on deploy and on every upgrade. A
+/// `start_timestamp` of 0 opens trading right away, no anti-snipe window or curve keeps
+/// the defaults of the bonding.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct LaunchSettings<M: ManagedTypeApi> {
+    pub start_timestamp: u64,
+    pub anti_snipe: Option<AntiSnipeConfig<M>>,
+    pub curve: Option<CurveParams<M>>,
+}
+
+/// The parameters a bonding is initialized with besides its db id and launch settings.
+/// The master keeps the ones of every bonding, as they stay what the bonding was deployed
+/// with until a value is pushed to it, whatever happens to the factory config meanwhile.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct BondingParams<M: ManagedTypeApi> {
+    pub allowed_token: TokenIdentifier<M>,
+    pub fees_collector: ManagedAddress<M>,
+    pub initial_virtual_liquidity: BigUint<M>,
+    pub oracle_address: ManagedAddress<M>,
+    pub max_market_cap: BigUint<M>,
+    pub jeetdex_router_sc_address: ManagedAddress<M>,
+    pub issue_token_cost: BigUint<M>,
+    pub wegld_unwrap_sc: ManagedAddress<M>,
+    pub reach_jeetdex_fee: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> BondingParams<M> {
+    pub fn from_config(config: &config::FactoryConfig<M>) -> Self {
+        BondingParams {
+            allowed_token: config.allowed_token.clone(),
+            fees_collector: config.fees_collector.clone(),
+            initial_virtual_liquidity: config.initial_virtual_liquidity.clone(),
+            oracle_address: config.oracle_address.clone(),
+            max_market_cap: config.max_market_cap.clone(),
+            jeetdex_router_sc_address: config.jeetdex_router_sc_address.clone(),
+            issue_token_cost: config.issue_token_cost.clone(),
+            wegld_unwrap_sc: config.wegld_unwrap_sc.clone(),
+            reach_jeetdex_fee: config.reach_jeetdex_fee.clone(),
+        }
+    }
+}
+
 #[derive(ManagedVecItem, TopEncode, TopDecode, PartialEq, TypeAbi)]
 pub struct PairContractMetadata<M: ManagedTypeApi> {
-    first_token_id: TokenIdentifier<M>,
-    second_token_id: TokenIdentifier<M>,
-    address: ManagedAddress<M>,
+    pub first_token_id: TokenIdentifier<M>,
+    pub second_token_id: TokenIdentifier<M>,
+    pub address: ManagedAddress<M>,
 }
 
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, Clone,ManagedVecItem)]
@@ -49,63 +91,80 @@ pub struct PairContractData<M: ManagedTypeApi> {
 #[multiversx_sc::module]
 pub trait FactoryModule: config::ConfigModule {
     #[proxy]
-    fn bonding_deploy_proxy(&self) -> bonding::Proxy<Self::Api>;
+    fn bonding_deploy_proxy(&self) -> bonding_proxy::Proxy<Self::Api>;
+    #[proxy]
+    fn bonding_view_proxy(&self, to: ManagedAddress) -> bonding_proxy::Proxy<Self::Api>;
     #[proxy]
-    fn bonding_view_proxy(&self, to: ManagedAddress) -> bonding::Proxy<Self::Api>;
+    fn bonding_contract_proxy(&self, to: ManagedAddress) -> bonding_proxy::Proxy<Self::Api>;
+    #[proxy]
+    fn bonding_launch_proxy(&self, to: ManagedAddress) -> crate::bonding_launch_proxy::Proxy<Self::Api>;
 
 
 
     fn create_bonding(
         &self,
-        db_id: ManagedBuffer
+        db_id: ManagedBuffer,
+        launch_settings: &LaunchSettings<Self::Api>,
+        gas_limit: u64
     ) -> ManagedAddress {
         require!(
             !self.pair_template_address().is_empty(),
             "pair contract template is empty"
         );
 
+        let params = BondingParams::from_config(&self.get_config());
         let (new_address, ()) = self
             .bonding_deploy_proxy()
             .init(
-                self.allowed_token().get(),
-                self.fees_collector().get(),
-                self.initial_virtual_liquidity().get(),
+                params.allowed_token.clone(),
+                params.fees_collector.clone(),
+                params.initial_virtual_liquidity.clone(),
                 // self.dex_token_fee().get(),
-                self.oracle_address().get(),
-                self.max_market_cap().get(),
-                self.jeetdex_router_sc_address().get(),
-                self.issue_token_cost().get(),
-                self.wegld_unwrap_sc().get(),
-                self.reach_jeetdex_fee().get(),
-                db_id
+                params.oracle_address.clone(),
+                params.max_market_cap.clone(),
+                params.jeetdex_router_sc_address.clone(),
+                params.issue_token_cost.clone(),
+                params.wegld_unwrap_sc.clone(),
+                params.reach_jeetdex_fee.clone(),
+                db_id,
+                launch_settings.clone(),
             )
+            .with_gas_limit(gas_limit)
             .deploy_from_source(
                 &self.pair_template_address().get(),
                 CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE | CodeMetadata::PAYABLE_BY_SC,
             );
+        self.bonding_params(&new_address).set(&params);
 
         new_address
     }
 
+    /// The upgrade runs after the current transaction, so anything sent to the bonding
+    /// before it is overwritten. `params` and `launch_settings` are the ones the bonding
+    /// should keep.
     fn upgrade_bonding(
         &self,
-        bonding_address: ManagedAddress
+        bonding_address: ManagedAddress,
+        params: &BondingParams<Self::Api>,
+        launch_settings: &LaunchSettings<Self::Api>,
     ) {
+        self.bonding_params(&bonding_address).set(params);
 
         self.bonding_deploy_proxy()
             .contract(bonding_address)
             .init(
-                self.allowed_token().get(),
-                self.fees_collector().get(),
-                self.initial_virtual_liquidity().get(),
+                params.allowed_token.clone(),
+                params.fees_collector.clone(),
+                params.initial_virtual_liquidity.clone(),
                 // self.dex_token_fee().get(),
-                self.oracle_address().get(),
-                self.max_market_cap().get(),
-                self.jeetdex_router_sc_address().get(),
-                self.issue_token_cost().get(),
-                self.wegld_unwrap_sc().get(),
-                self.reach_jeetdex_fee().get(),
-                ManagedBuffer::new()
+                params.oracle_address.clone(),
+                params.max_market_cap.clone(),
+                params.jeetdex_router_sc_address.clone(),
+                params.issue_token_cost.clone(),
+                params.wegld_unwrap_sc.clone(),
+                params.reach_jeetdex_fee.clone(),
+                ManagedBuffer::new(),
+                launch_settings.clone(),
             )
             .upgrade_from_source(
                 &self.pair_template_address().get(),
@@ -113,6 +172,30 @@ pub trait FactoryModule: config::ConfigModule {
             );
     }
 
+    /// The parameters the bonding currently runs with. Bondings deployed before they were
+    /// recorded are taken to run with the factory config.
+    #[view(getBondingParams)]
+    fn get_bonding_params(&self, bonding_address: ManagedAddress) -> BondingParams<Self::Api> {
+        let params_mapper = self.bonding_params(&bonding_address);
+        if params_mapper.is_empty() {
+            return BondingParams::from_config(&self.get_config());
+        }
+
+        params_mapper.get()
+    }
+
+    #[storage_mapper("bonding_params")]
+    fn bonding_params(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<BondingParams<Self::Api>>;
+
+    fn is_graduated(&self, bonding_address: &ManagedAddress) -> bool {
+        !self.dex_pair(bonding_address).is_empty()
+    }
+
+    /// The JeetDex pair a bonding graduated to.
+    #[view(getDexPair)]
+    #[storage_mapper("dex_pair")]
+    fn dex_pair(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
 
 
 
@@ -133,7 +216,7 @@ pub trait FactoryModule: config::ConfigModule {
     #[view(getAllBondingData)]
     fn get_all_pair_contract_data(&self) -> MultiValueEncoded<PairContractData<Self::Api>> {
         let mut result = MultiValueEncoded::new();
-        for (k, v) in self.pair_map().iter() {
+        for (_, v) in self.pair_map().iter() {
             
             let pair_data: PairData<Self::Api> = self.bonding_view_proxy(v.clone()).get_pair_data().execute_on_dest_context_readonly();
 
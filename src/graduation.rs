@@ -0,0 +1,194 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config::{self, DEAD_ADDRESS};
+use crate::creator;
+use crate::factory;
+use crate::profile;
+use crate::ranking;
+use crate::stats;
+use crate::treasury::{self, FeeSource};
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq)]
+pub enum LpPolicy {
+    Burn,
+    Lock,
+    Treasury,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct GraduationPolicy {
+    pub lp_policy: LpPolicy,
+    pub lock_epochs: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct LpLock<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub unlock_epoch: u64,
+}
+
+#[multiversx_sc::module]
+pub trait GraduationModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + profile::LaunchProfileModule
+    + ranking::RankingModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+{
+    /// Called by a bonding once its liquidity has been migrated to JeetDex, with the
+    /// resulting LP tokens if the bonding has a graduation policy.
+    #[payable("*")]
+    #[endpoint(registerDexPair)]
+    fn register_dex_pair(&self, dex_pair_address: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.check_is_pair_sc(&caller);
+        self.set_dex_pair(&caller, &dex_pair_address);
+
+        let payments = self.call_value().all_esdt_transfers();
+        if !payments.is_empty() {
+            require!(payments.len() == 1, "Only one LP token payment is accepted");
+            self.apply_graduation_policy(&caller, payments.get(0));
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(setDexPairForBonding)]
+    fn set_dex_pair_for_bonding(&self, bonding_address: ManagedAddress, dex_pair_address: ManagedAddress) {
+        self.check_is_pair_sc(&bonding_address);
+        self.set_dex_pair(&bonding_address, &dex_pair_address);
+    }
+
+    fn set_dex_pair(&self, bonding_address: &ManagedAddress, dex_pair_address: &ManagedAddress) {
+        require!(!dex_pair_address.is_zero(), "Dex pair cannot be zero address");
+        require!(self.dex_pair(bonding_address).is_empty(), "Dex pair already registered");
+
+        self.dex_pair(bonding_address).set(dex_pair_address);
+        self.on_bonding_graduated(bonding_address);
+        self.remove_from_rankings(bonding_address);
+    }
+
+    #[only_owner]
+    #[endpoint(setProfileGraduationPolicy)]
+    fn set_profile_graduation_policy(&self, profile_id: u32, lp_policy: LpPolicy, lock_epochs: u64) {
+        self.require_launch_profile(profile_id);
+        require!(
+            lp_policy != LpPolicy::Lock || lock_epochs > 0,
+            "Lock epochs cannot be zero"
+        );
+
+        self.profile_graduation_policy(profile_id)
+            .set(GraduationPolicy { lp_policy, lock_epochs });
+    }
+
+    #[only_owner]
+    #[endpoint(clearProfileGraduationPolicy)]
+    fn clear_profile_graduation_policy(&self, profile_id: u32) {
+        self.profile_graduation_policy(profile_id).clear();
+    }
+
+    fn record_graduation_policy(&self, bonding_address: &ManagedAddress, profile_id: u32) {
+        let profile_mapper = self.profile_graduation_policy(profile_id);
+        if !profile_mapper.is_empty() {
+            self.bonding_graduation_policy(bonding_address).set(profile_mapper.get());
+        }
+    }
+
+    /// Burned LP tokens are sent to DEAD_ADDRESS: burning an ESDT requires a local burn
+    /// role on the LP token, which the master does not hold.
+    fn apply_graduation_policy(&self, bonding_address: &ManagedAddress, lp_payment: EsdtTokenPayment) {
+        let policy_mapper = self.bonding_graduation_policy(bonding_address);
+        require!(!policy_mapper.is_empty(), "No graduation policy for this bonding");
+
+        require!(lp_payment.token_nonce == 0, "LP token must be fungible");
+
+        let policy = policy_mapper.get();
+        match policy.lp_policy {
+            LpPolicy::Burn => {
+                self.send().direct_esdt(
+                    &ManagedAddress::from(&DEAD_ADDRESS),
+                    &lp_payment.token_identifier,
+                    0,
+                    &lp_payment.amount,
+                );
+                self.burned_lp(bonding_address).set(&lp_payment);
+            },
+            LpPolicy::Lock => {
+                self.add_owed_esdt(&lp_payment.token_identifier, &lp_payment.amount);
+                self.lp_lock(bonding_address).set(LpLock {
+                    token_id: lp_payment.token_identifier,
+                    amount: lp_payment.amount,
+                    unlock_epoch: self.blockchain().get_block_epoch() + policy.lock_epochs,
+                });
+            },
+            LpPolicy::Treasury => {
+                self.accrue_fees(
+                    FeeSource::GraduationLp,
+                    &EgldOrEsdtTokenIdentifier::esdt(lp_payment.token_identifier),
+                    &lp_payment.amount,
+                );
+            },
+        }
+    }
+
+    /// Sends the LP tokens of an expired lock to the current creator of the token.
+    #[endpoint(releaseLockedLp)]
+    fn release_locked_lp(&self, token_id: TokenIdentifier) {
+        let bonding_address = self.require_token_creator(&token_id);
+        let lp_lock_mapper = self.lp_lock(&bonding_address);
+        require!(!lp_lock_mapper.is_empty(), "No locked LP for this token");
+
+        let lp_lock = lp_lock_mapper.take();
+        require!(
+            self.blockchain().get_block_epoch() >= lp_lock.unlock_epoch,
+            "LP tokens are still locked"
+        );
+        self.remove_owed_esdt(&lp_lock.token_id, &lp_lock.amount);
+
+        self.send().direct_esdt(
+            &self.blockchain().get_caller(),
+            &lp_lock.token_id,
+            0,
+            &lp_lock.amount,
+        );
+    }
+
+    #[view(getDexPairForToken)]
+    fn get_dex_pair_for_token(&self, token_id: TokenIdentifier) -> OptionalValue<ManagedAddress> {
+        let bonding_address = self.get_pair(token_id, self.allowed_token().get());
+        if bonding_address.is_zero() || self.dex_pair(&bonding_address).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.dex_pair(&bonding_address).get())
+    }
+
+    #[view(getLpLockForToken)]
+    fn get_lp_lock_for_token(&self, token_id: TokenIdentifier) -> OptionalValue<LpLock<Self::Api>> {
+        let bonding_address = self.get_pair(token_id, self.allowed_token().get());
+        if bonding_address.is_zero() || self.lp_lock(&bonding_address).is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.lp_lock(&bonding_address).get())
+    }
+
+    #[view(getProfileGraduationPolicy)]
+    #[storage_mapper("profile_graduation_policy")]
+    fn profile_graduation_policy(&self, profile_id: u32) -> SingleValueMapper<GraduationPolicy>;
+
+    #[view(getBondingGraduationPolicy)]
+    #[storage_mapper("bonding_graduation_policy")]
+    fn bonding_graduation_policy(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<GraduationPolicy>;
+
+    #[view(getLpLock)]
+    #[storage_mapper("lp_lock")]
+    fn lp_lock(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<LpLock<Self::Api>>;
+
+    #[view(getBurnedLp)]
+    #[storage_mapper("burned_lp")]
+    fn burned_lp(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<EsdtTokenPayment>;
+}
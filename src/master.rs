@@ -3,24 +3,68 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod airdrop;
+pub mod allocation;
+pub mod anti_snipe;
+pub mod bonding_overrides;
 pub mod config;
+pub mod creator;
+pub mod creator_fees;
+pub mod curve;
 pub mod factory;
-
-use bonding::pair_actions::swap::ProxyTrait as _;
-use factory::PairTokens;
-
-use bonding::ProxyTrait as _;
-use bonding::config::ProxyTrait as _;
-
-use router::config::ProxyTrait as _;
+pub mod graduation;
+pub mod metadata;
+pub mod migration;
+pub mod profile;
+pub mod ranking;
+pub mod rate_limit;
+pub mod schedule;
+pub mod stats;
+pub mod treasury;
+pub mod vesting;
+
+use factory::{LaunchSettings, PairTokens};
+use metadata::TokenMetadata;
+use treasury::FeeSource;
+
+use bonding_proxy::ProxyTrait as _;
+use router_proxy::ProxyTrait as _;
 
 const TOKEN_DECIMALS: usize = 18;
 
+/// What `newToken` records for the issue callback on top of the caller, db id and creator
+/// buy flag, the only arguments of the callbacks issued before the launch options existed.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct LaunchRequest<M: ManagedTypeApi> {
+    pub metadata: TokenMetadata<M>,
+    pub start_timestamp: u64,
+    pub launch_profile: u32,
+    pub launch_fee: BigUint<M>,
+    pub issue_cost: BigUint<M>,
+}
+
 
 #[multiversx_sc::contract]
 pub trait MasterContract:
     config::ConfigModule 
     + factory::FactoryModule
+    + creator::CreatorModule
+    + creator_fees::CreatorFeesModule
+    + graduation::GraduationModule
+    + metadata::MetadataModule
+    + migration::MigrationModule
+    + profile::LaunchProfileModule
+    + anti_snipe::AntiSnipeModule
+    + ranking::RankingModule
+    + rate_limit::RateLimitModule
+    + schedule::ScheduleModule
+    + stats::StatsModule
+    + vesting::VestingModule
+    + allocation::AllocationModule
+    + airdrop::AirdropModule
+    + curve::CurveModule
+    + bonding_overrides::BondingOverridesModule
+    + treasury::TreasuryModule
 {
 
     #[init]
@@ -41,34 +85,30 @@ pub trait MasterContract:
 
     ) {
         self.state().set_if_empty(true);
-        require!(pair_template_address != ManagedAddress::zero(), "template cannot be zero");
-        require!(token_supply > 0, "Token Supply cannot be zero");
-        require!(new_token_fee > 0, "Token Fee cannot be zero");
-        require!(initial_virtual_liquidity > 0, "IVL cannot be zero");
-        // require!(dex_token_fee > 0, "Dex Token Fee cannot be zero");
-        require!(pair_template_address != ManagedAddress::zero(), "oracle cannot be zero");
-        require!(max_market_cap > 0, "Max MarketCap cannot be zero");
-        require!(issue_token_cost > 0, "issue_token_cost cannot be zero");
-        require!(wegld_unwrap_sc != ManagedAddress::zero(), "wegld_unwrap_sc cannot be zero");
-        require!(jeetdex_router_sc_address != ManagedAddress::zero(), "jeetdex_router_sc_address cannot be zero");
-        require!(reach_jeetdex_fee > 0, "reach_jeetdex_fee cannot be zero");
-        
-        self.pair_template_address().set_if_empty(pair_template_address);
-        self.token_supply().set_if_empty(token_supply);
-        self.new_token_fee().set_if_empty(new_token_fee);
-        self.fees_collector().set_if_empty(fees_collector);
-        self.initial_virtual_liquidity().set_if_empty(initial_virtual_liquidity);
+        require!(initial_token_to_buy_with.is_valid_esdt_identifier(), "Invalid token to buy with");
         self.allowed_token().set_if_empty(initial_token_to_buy_with);
-        // self.dex_token_fee().set_if_empty(dex_token_fee);
-        self.oracle_address().set_if_empty(oracle_address);
-        self.max_market_cap().set_if_empty(max_market_cap);
-        self.jeetdex_router_sc_address().set_if_empty(jeetdex_router_sc_address);
-        self.issue_token_cost().set_if_empty(issue_token_cost);
-        self.wegld_unwrap_sc().set_if_empty(wegld_unwrap_sc);
-        self.reach_jeetdex_fee().set_if_empty(reach_jeetdex_fee);
+
+        self.apply_config(&config::FactoryConfig {
+            pair_template_address,
+            token_supply,
+            fees_collector,
+            new_token_fee,
+            initial_virtual_liquidity,
+            allowed_token: self.allowed_token().get(),
+            oracle_address,
+            max_market_cap,
+            jeetdex_router_sc_address,
+            issue_token_cost,
+            wegld_unwrap_sc,
+            reach_jeetdex_fee,
+            gas_schedule: config::GasSchedule::default_schedule(),
+        });
+        self.storage_version().set_if_empty(migration::CURRENT_STORAGE_VERSION);
     }
 
-    #[endpoint]
+    /// The factory stays paused after an upgrade until the owner has run the pending
+    /// migrations with `runMigrations` and resumes it.
+    #[upgrade]
     fn upgrade(&self) {
         self.state().set(false);
     }
@@ -80,6 +120,9 @@ pub trait MasterContract:
             self.state().set(false);
         } else {
             self.check_is_pair_sc(&address);
+            if !self.is_graduated(&address) {
+                self.on_bonding_paused(&address);
+            }
             let _: IgnoreValue = self
                 .bonding_contract_proxy(address)
                 .pause()
@@ -95,9 +138,12 @@ pub trait MasterContract:
                 self.pair_map().len() == self.address_pair_map().len(),
                 "The size of the 2 pair maps is not the same"
             );
+            self.require_migrations_done();
             self.state().set(true);
         } else {
             self.check_is_pair_sc(&address);
+            require!(!self.cancelled_launch(&address).get(), "Launch was cancelled");
+            self.on_bonding_resumed(&address);
             let _: IgnoreValue = self
                 .bonding_contract_proxy(address)
                 .resume()
@@ -105,15 +151,35 @@ pub trait MasterContract:
         }
     }
 
+    /// Also clears the settings stored for the profile, so that a profile added again
+    /// under the same id starts from none. Bondings launched with it keep theirs.
+    #[only_owner]
+    #[endpoint(removeLaunchProfile)]
+    fn remove_launch_profile(&self, profile_id: u32) {
+        require!(self.launch_profiles().swap_remove(&profile_id), "Profile does not exist");
+
+        self.profile_anti_snipe(profile_id).clear();
+        self.profile_curve(profile_id).clear();
+        self.profile_supply_allocation(profile_id).clear();
+        self.profile_graduation_policy(profile_id).clear();
+        self.profile_creator_buy_vesting(profile_id).clear();
+    }
+
     #[only_owner]
     #[endpoint(setJeetDexRouter)]
     fn set_jeetdex_router(&self, address: ManagedAddress, jeet_router_address: ManagedAddress) {
         
 
         if address == self.blockchain().get_sc_address() {
-            self.jeetdex_router_sc_address().set(&jeet_router_address);
+            let mut config = self.get_config();
+            config.jeetdex_router_sc_address = jeet_router_address;
+            self.apply_config(&config);
         } else {
             self.check_is_pair_sc(&address);
+            let mut bonding_params = self.get_bonding_params(address.clone());
+            bonding_params.jeetdex_router_sc_address = jeet_router_address.clone();
+            self.bonding_params(&address).set(bonding_params);
+
             let _: IgnoreValue = self
                 .bonding_contract_proxy(address)
                 .set_jeetdex_router(jeet_router_address)
@@ -126,13 +192,17 @@ pub trait MasterContract:
 
 
     #[payable("EGLD")]
+    #[allow_multiple_var_args]
     #[endpoint(newToken)]
     fn create_new_token_endpoint(
         &self,
         token_display_name: ManagedBuffer,
         token_ticker: ManagedBuffer,
         db_id: ManagedBuffer,
-        token_creator_buy: bool
+        token_creator_buy: bool,
+        opt_metadata: OptionalValue<TokenMetadata<Self::Api>>,
+        opt_start_timestamp: OptionalValue<u64>,
+        opt_launch_profile: OptionalValue<u32>,
     ) {
         require!(self.is_active(), "Not active");
         let new_token_fee_cost = self.call_value().egld_value().clone_value();
@@ -148,17 +218,36 @@ pub trait MasterContract:
         }
 
         let caller = self.blockchain().get_caller();
+        self.check_creator_allowed(&caller);
+        self.check_and_record_launch(&caller);
 
         require!(self.token_supply().get() > 0, "Token Supply cannot be zero");
 
-        if self.blockchain().get_gas_left() > 150000000 {
+        let metadata = opt_metadata.into_option().unwrap_or_else(TokenMetadata::empty);
+        self.validate_token_metadata(&metadata);
+
+        let start_timestamp = opt_start_timestamp.into_option().unwrap_or_default();
+        self.validate_start_timestamp(start_timestamp);
+
+        let launch_profile = opt_launch_profile
+            .into_option()
+            .unwrap_or(profile::DEFAULT_LAUNCH_PROFILE);
+        self.require_launch_profile(launch_profile);
+
+        let issue_cost = self.issue_token_cost().get();
+        // the part of the fee kept by the master is owed to the caller until the callback
+        self.add_owed(&EgldOrEsdtTokenIdentifier::egld(), &(&new_token_fee_cost - &issue_cost));
+
+        let gas_schedule = self.get_gas_schedule();
+        let gas_left = self.blockchain().get_gas_left();
+        if gas_left >= gas_schedule.issue_and_callback_gas() {
             self.send()
                 .esdt_system_sc_proxy()
                 .issue_fungible(
-                    self.issue_token_cost().get(),
+                    issue_cost.clone(),
                     &token_display_name,
                     &token_ticker,
-                    &BigUint::from(self.token_supply().get()),
+                    &self.token_supply().get(),
                     FungibleTokenProperties {
                         num_decimals: TOKEN_DECIMALS,
                         can_freeze: false,
@@ -171,15 +260,29 @@ pub trait MasterContract:
                         can_add_special_roles: false,
                     },
                 )
-                .with_gas_limit(150000000)
                 .async_call()
                 .with_callback(
                     self.callbacks()
-                        .token_issue_callback(&caller,&db_id,&token_creator_buy),
+                        .token_issue_callback(
+                            &caller,
+                            &db_id,
+                            &token_creator_buy,
+                            OptionalValue::Some(LaunchRequest {
+                                metadata,
+                                start_timestamp,
+                                launch_profile,
+                                launch_fee: new_token_fee_cost,
+                                issue_cost,
+                            }),
+                        ),
                 )
                 .call_and_exit();
         }else{
-            sc_panic!("Not enough gas left {}",self.blockchain().get_gas_left());
+            sc_panic!(
+                "Not enough gas left {}, at least {} needed",
+                gas_left,
+                gas_schedule.issue_and_callback_gas()
+            );
         }
 
     }
@@ -214,9 +317,20 @@ pub trait MasterContract:
         require!(!bonding_address.is_zero(), "Bonding does not exists");
 
 
-        self.upgrade_bonding(
-            bonding_address
-        );
+        let bonding_params = self.get_bonding_params(bonding_address.clone());
+        let launch_settings = self.get_bonding_launch_settings(&bonding_address);
+        self.upgrade_bonding(bonding_address, &bonding_params, &launch_settings);
+    }
+
+    /// The launch settings the bonding was deployed with.
+    fn get_bonding_launch_settings(&self, bonding_address: &ManagedAddress) -> LaunchSettings<Self::Api> {
+        let anti_snipe_mapper = self.bonding_anti_snipe(bonding_address);
+        let curve_mapper = self.bonding_curve(bonding_address);
+        LaunchSettings {
+            start_timestamp: self.launch_start_timestamp(bonding_address).get(),
+            anti_snipe: (!anti_snipe_mapper.is_empty()).then(|| anti_snipe_mapper.get()),
+            curve: (!curve_mapper.is_empty()).then(|| curve_mapper.get()),
+        }
     }
 
 
@@ -226,15 +340,50 @@ pub trait MasterContract:
         caller: &ManagedAddress,
         db_id: &ManagedBuffer,
         token_creator_buy: &bool,
+        opt_request: OptionalValue<LaunchRequest<Self::Api>>,
         #[call_result] result: ManagedAsyncCallResult<()>,
     ) {
         let (token_id, returned_tokens) = self.call_value().egld_or_single_fungible_esdt();
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        // an issuance pending during the upgrade has no request, it launches like the
+        // master it started on did, with the fees of the config and nothing owed for it
+        let request = match opt_request.into_option() {
+            Some(request) => {
+                self.remove_owed(&egld, &(&request.launch_fee - &request.issue_cost));
+                request
+            },
+            None => LaunchRequest {
+                metadata: TokenMetadata::empty(),
+                start_timestamp: 0,
+                launch_profile: profile::DEFAULT_LAUNCH_PROFILE,
+                launch_fee: self.new_token_fee().get(),
+                issue_cost: self.issue_token_cost().get(),
+            },
+        };
+        let metadata = &request.metadata;
+        let start_timestamp = &request.start_timestamp;
+        let launch_profile = &request.launch_profile;
+        let launch_fee = &request.launch_fee;
+        let issue_cost = &request.issue_cost;
         match result {
             ManagedAsyncCallResult::Ok(()) => {
-                let bonding_address = self.create_bonding(db_id.clone());
+                let is_scheduled = self.is_scheduled_start(*start_timestamp);
+                let launch_settings = LaunchSettings {
+                    start_timestamp: if is_scheduled { *start_timestamp } else { 0 },
+                    anti_snipe: self.get_profile_anti_snipe(*launch_profile),
+                    curve: self.get_profile_curve(*launch_profile),
+                };
+
+                let gas_schedule = self.get_gas_schedule();
+                let bonding_address = self.create_bonding(
+                    db_id.clone(),
+                    &launch_settings,
+                    gas_schedule.create_bonding,
+                );
                 
                 let _: IgnoreValue = self.jeetdex_router_proxy(self.jeetdex_router_sc_address().get())
                 .set_temp_degen_pair(bonding_address.clone())
+                .with_gas_limit(gas_schedule.register_router)
                 .execute_on_dest_context();
                 
                 self.pair_map().insert(
@@ -251,25 +400,87 @@ pub trait MasterContract:
                         second_token_id: self.allowed_token().get(),
                     },
                 );
+                self.all_bondings().insert(bonding_address.clone());
+                self.bonding_creator(&bonding_address).set(caller);
+                if !metadata.is_empty() {
+                    self.token_metadata(&token_id.clone().unwrap_esdt()).set(metadata);
+                }
+
+                // the fee paid and issue cost are carried over from `newToken`, the config
+                // may have changed while the issuance was pending
+                self.on_launch(launch_fee);
+
+                let remaining_fee = launch_fee - issue_cost;
+                if is_scheduled {
+                    self.schedule_launch(&bonding_address, *start_timestamp, &remaining_fee);
+                }
+
+                self.bonding_launch_profile(&bonding_address).set(launch_profile);
+                if let Some(anti_snipe) = &launch_settings.anti_snipe {
+                    self.bonding_anti_snipe(&bonding_address).set(anti_snipe);
+                }
+                self.record_graduation_policy(&bonding_address, *launch_profile);
+
+                let vesting_start = if is_scheduled {
+                    *start_timestamp
+                } else {
+                    self.blockchain().get_block_timestamp()
+                };
+                let curve_supply = self.split_issued_supply(
+                    &token_id.clone().unwrap_esdt(),
+                    *launch_profile,
+                    &returned_tokens,
+                    vesting_start,
+                );
+                self.bonding_curve_supply(&bonding_address).set(&curve_supply);
+                if let Some(curve) = &launch_settings.curve {
+                    self.bonding_curve(&bonding_address).set(curve);
+                }
             
+                // a vested creator buy is sent to the master, which locks it for the creator
+                let creator_buy_vesting = if *token_creator_buy {
+                    self.get_profile_creator_buy_vesting(*launch_profile)
+                } else {
+                    None
+                };
+                let buy_recipient = if creator_buy_vesting.is_some() {
+                    OptionalValue::Some(self.blockchain().get_sc_address())
+                } else {
+                    OptionalValue::None
+                };
+                let balance_before = self.blockchain().get_sc_balance(&token_id, 0);
+
                 let _: IgnoreValue = self
                     .bonding_contract_proxy(bonding_address.clone())
-                    .set_token_identifier(token_creator_buy,caller)
+                    .set_token_identifier(token_creator_buy, caller.clone(), buy_recipient)
                     // .with_multi_token_transfer(payments)
-                    .with_esdt_transfer(EsdtTokenPayment::new(token_id.clone().unwrap_esdt(), 0, returned_tokens))
+                    .with_esdt_transfer(EsdtTokenPayment::new(token_id.clone().unwrap_esdt(), 0, curve_supply.clone()))
+                    .with_gas_limit(gas_schedule.set_token_identifier)
                     .execute_on_dest_context();
-                
 
-                let remaining_fee = self.new_token_fee().get() - self.issue_token_cost().get();
-                if remaining_fee > 0{
-                    self.send().direct_egld(&self.fees_collector().get(), &remaining_fee);
+                if let Some(vesting) = creator_buy_vesting {
+                    let bought = self.blockchain().get_sc_balance(&token_id, 0) + &curve_supply - balance_before;
+                    if bought > 0 {
+                        self.add_vesting_schedule(
+                            &token_id.clone().unwrap_esdt(),
+                            &bought,
+                            vesting_start,
+                            vesting.cliff_duration,
+                            vesting.vesting_duration,
+                        );
+                    }
+                }
+
+                if !is_scheduled {
+                    self.accrue_fees(FeeSource::LaunchFee, &egld, &remaining_fee);
                 }
 
 
             }
             ManagedAsyncCallResult::Err(_) => {
                 if token_id.is_egld() && returned_tokens > 0u64 {
-                    self.send().direct_egld(caller, &self.new_token_fee().get());
+                    self.send().direct_egld(caller, launch_fee);
+                    self.on_refund(launch_fee);
                 }
             }
         }
@@ -279,9 +490,7 @@ pub trait MasterContract:
     #[proxy]
     fn oracle_proxy(&self, to: ManagedAddress) -> oracle_proxy::Proxy<Self::Api>;
     #[proxy]
-    fn jeetdex_router_proxy(&self, to: ManagedAddress) -> router::Proxy<Self::Api>;
-    #[proxy]
-    fn bonding_contract_proxy(&self, to: ManagedAddress) -> bonding::Proxy<Self::Api>;
+    fn jeetdex_router_proxy(&self, to: ManagedAddress) -> router_proxy::Proxy<Self::Api>;
 
 }
 
@@ -289,7 +498,7 @@ mod oracle_proxy {
     multiversx_sc::imports!();
 
     #[multiversx_sc::proxy]
-   pub trait xExchangeOracleContract {
+   pub trait XExchangeOracleContract {
         #[view(getAmountOut)]
         fn get_amount_out_view(&self, token_in: TokenIdentifier, amount_in: BigUint);
 
@@ -305,4 +514,99 @@ mod oracle_proxy {
         #[endpoint(wrapEgld)]
         fn wrap_egld(&self) -> EsdtTokenPayment;
     }
-}
\ No newline at end of file
+}
+
+mod router_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait JeetDexRouterContract {
+        #[endpoint(setTempDegenPair)]
+        fn set_temp_degen_pair(&self, address: ManagedAddress);
+    }
+}
+
+/// The part of the bonding template the master deploys, upgrades and drives.
+pub mod bonding_proxy {
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone, Copy, PartialEq, Debug)]
+    pub enum State {
+        Inactive,
+        Active,
+        PartialActive,
+    }
+
+    use crate::factory::LaunchSettings;
+
+    #[multiversx_sc::proxy]
+    pub trait BondingContract {
+        #[init]
+        fn init(
+            &self,
+            allowed_token: TokenIdentifier,
+            fees_collector: ManagedAddress,
+            initial_virtual_liquidity: BigUint,
+            oracle_address: ManagedAddress,
+            max_market_cap: BigUint,
+            jeetdex_router_sc_address: ManagedAddress,
+            issue_token_cost: BigUint,
+            wegld_unwrap_sc: ManagedAddress,
+            reach_jeetdex_fee: BigUint,
+            db_id: ManagedBuffer,
+            launch_settings: LaunchSettings<Self::Api>,
+        );
+
+        /// The creator buy goes to `opt_buy_recipient` when given, else to `creator`.
+        #[payable("*")]
+        #[endpoint(setTokenIdentifier)]
+        fn set_token_identifier(
+            &self,
+            token_creator_buy: bool,
+            creator: ManagedAddress,
+            opt_buy_recipient: OptionalValue<ManagedAddress>,
+        );
+
+        #[endpoint]
+        fn pause(&self);
+
+        #[endpoint]
+        fn resume(&self);
+
+        #[endpoint(setJeetDexRouter)]
+        fn set_jeetdex_router(&self, address: ManagedAddress);
+
+        #[view(getPairData)]
+        fn get_pair_data(&self);
+    }
+}
+
+pub mod bonding_launch_proxy {
+    multiversx_sc::imports!();
+
+    /// Parameters the master pushes to live bondings, and the reserve withdrawal of cancelled
+    /// launches.
+    #[multiversx_sc::proxy]
+    pub trait BondingLaunchContract {
+        /// Sends the whole token reserve of a paused bonding back to the master and returns
+        /// the amount sent.
+        #[endpoint(withdrawTokenReserve)]
+        fn withdraw_token_reserve(&self) -> BigUint;
+
+        #[endpoint(setFeesCollector)]
+        fn set_fees_collector(&self, fees_collector: ManagedAddress);
+
+        #[endpoint(setOracleAddress)]
+        fn set_oracle_address(&self, oracle_address: ManagedAddress);
+
+        #[endpoint(setMaxMarketCap)]
+        fn set_max_market_cap(&self, max_market_cap: BigUint);
+
+        #[endpoint(setReachJeetdexFee)]
+        fn set_reach_jeetdex_fee(&self, reach_jeetdex_fee: BigUint);
+
+        #[endpoint(setWegldUnwrapSc)]
+        fn set_wegld_unwrap_sc(&self, wegld_unwrap_sc: ManagedAddress);
+    }
+}
@@ -0,0 +1,99 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::creator;
+use crate::factory;
+
+const MAX_DESCRIPTION_LENGTH: usize = 1_000;
+const MAX_FIELD_LENGTH: usize = 256;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct TokenMetadata<M: ManagedTypeApi> {
+    pub description: ManagedBuffer<M>,
+    pub image_uri: ManagedBuffer<M>,
+    pub image_hash: ManagedBuffer<M>,
+    pub website: ManagedBuffer<M>,
+    pub twitter: ManagedBuffer<M>,
+    pub telegram: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi> TokenMetadata<M> {
+    pub fn empty() -> Self {
+        TokenMetadata {
+            description: ManagedBuffer::new(),
+            image_uri: ManagedBuffer::new(),
+            image_hash: ManagedBuffer::new(),
+            website: ManagedBuffer::new(),
+            twitter: ManagedBuffer::new(),
+            telegram: ManagedBuffer::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.description.is_empty()
+            && self.image_uri.is_empty()
+            && self.image_hash.is_empty()
+            && self.website.is_empty()
+            && self.twitter.is_empty()
+            && self.telegram.is_empty()
+    }
+}
+
+#[multiversx_sc::module]
+pub trait MetadataModule:
+    config::ConfigModule + factory::FactoryModule + creator::CreatorModule
+{
+    #[endpoint(setTokenMetadata)]
+    fn set_token_metadata(&self, token_id: TokenIdentifier, metadata: TokenMetadata<Self::Api>) {
+        self.require_token_creator(&token_id);
+        require!(
+            !self.token_metadata_locked(&token_id).get(),
+            "Token metadata is locked by moderation"
+        );
+
+        self.validate_token_metadata(&metadata);
+        self.token_metadata(&token_id).set(&metadata);
+    }
+
+    /// Lets the owner overwrite or clear offending metadata. A locked entry can no
+    /// longer be edited by the creator.
+    #[only_owner]
+    #[endpoint(moderateTokenMetadata)]
+    fn moderate_token_metadata(
+        &self,
+        token_id: TokenIdentifier,
+        metadata: TokenMetadata<Self::Api>,
+        lock: bool,
+    ) {
+        self.require_launched_token(&token_id);
+
+        self.validate_token_metadata(&metadata);
+        if metadata.is_empty() {
+            self.token_metadata(&token_id).clear();
+        } else {
+            self.token_metadata(&token_id).set(&metadata);
+        }
+        self.token_metadata_locked(&token_id).set(lock);
+    }
+
+    fn validate_token_metadata(&self, metadata: &TokenMetadata<Self::Api>) {
+        require!(
+            metadata.description.len() <= MAX_DESCRIPTION_LENGTH,
+            "Description is too long"
+        );
+        require!(metadata.image_uri.len() <= MAX_FIELD_LENGTH, "Image URI is too long");
+        require!(metadata.image_hash.len() <= MAX_FIELD_LENGTH, "Image hash is too long");
+        require!(metadata.website.len() <= MAX_FIELD_LENGTH, "Website is too long");
+        require!(metadata.twitter.len() <= MAX_FIELD_LENGTH, "Twitter link is too long");
+        require!(metadata.telegram.len() <= MAX_FIELD_LENGTH, "Telegram link is too long");
+    }
+
+    #[view(getTokenMetadata)]
+    #[storage_mapper("token_metadata")]
+    fn token_metadata(&self, token_id: &TokenIdentifier) -> SingleValueMapper<TokenMetadata<Self::Api>>;
+
+    #[view(isTokenMetadataLocked)]
+    #[storage_mapper("token_metadata_locked")]
+    fn token_metadata_locked(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+}
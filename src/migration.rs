@@ -0,0 +1,122 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::factory;
+use crate::stats;
+
+/// Bump this and add a branch to `run_migration` whenever a storage layout changes.
+pub const CURRENT_STORAGE_VERSION: u32 = 2;
+
+/// Where the migration in progress stopped.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct MigrationCursor<M: ManagedTypeApi> {
+    pub last_address: Option<ManagedAddress<M>>,
+}
+
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + stats::StatsModule
+{
+    /// Runs the pending migrations, in order, over at most `max_items` items and returns
+    /// how many migrations are still pending. The upgrade leaves the master paused and it
+    /// cannot be resumed before this returns 0.
+    #[only_owner]
+    #[endpoint(runMigrations)]
+    fn run_migrations(&self, max_items: usize) -> u32 {
+        require!(max_items > 0, "Batch size cannot be zero");
+
+        let mut items_left = max_items;
+        let mut version = self.storage_version().get();
+        while version < CURRENT_STORAGE_VERSION && items_left > 0 {
+            if !self.run_migration(version + 1, &mut items_left) {
+                break;
+            }
+
+            version += 1;
+            self.storage_version().set(version);
+            self.migration_cursor().clear();
+        }
+        CURRENT_STORAGE_VERSION - version
+    }
+
+    /// Returns whether the migration is complete.
+    fn run_migration(&self, version: u32, items_left: &mut usize) -> bool {
+        match version {
+            1 => self.migrate_v1_launch_stats(items_left),
+            2 => self.migrate_v2_all_bondings(items_left),
+            _ => sc_panic!("Unknown storage migration {}", version),
+        }
+    }
+
+    fn get_migration_cursor(&self) -> MigrationCursor<Self::Api> {
+        let cursor_mapper = self.migration_cursor();
+        if cursor_mapper.is_empty() {
+            return MigrationCursor { last_address: None };
+        }
+
+        cursor_mapper.get()
+    }
+
+    /// Launches made before the platform stats existed were never counted. Every pair is
+    /// one launch, including those of issuances resolved since the upgrade, which
+    /// `on_launch` already counted on top of nothing.
+    fn migrate_v1_launch_stats(&self, items_left: &mut usize) -> bool {
+        self.total_launches().set(self.pair_map().len() as u64);
+        *items_left -= 1;
+        true
+    }
+
+    /// Bondings launched before `all_bondings` existed are only in the pair maps.
+    fn migrate_v2_all_bondings(&self, items_left: &mut usize) -> bool {
+        let mut cursor = self.get_migration_cursor();
+        let pair_addresses = self.address_pair_keys();
+        let mut addresses = match &cursor.last_address {
+            Some(last_address) => {
+                let mut addresses = pair_addresses.iter_from(last_address);
+                let _ = addresses.next();
+                addresses
+            },
+            None => pair_addresses.iter(),
+        };
+
+        while *items_left > 0 {
+            match addresses.next() {
+                Some(bonding_address) => {
+                    self.all_bondings().insert(bonding_address.clone());
+                    cursor.last_address = Some(bonding_address);
+                    *items_left -= 1;
+                },
+                None => return true,
+            }
+        }
+        if addresses.next().is_none() {
+            return true;
+        }
+
+        self.migration_cursor().set(cursor);
+        false
+    }
+
+    fn require_migrations_done(&self) {
+        require!(
+            self.storage_version().get() == CURRENT_STORAGE_VERSION,
+            "Storage migrations are pending"
+        );
+    }
+
+    #[view(getMigrationCursor)]
+    #[storage_mapper("migration_cursor")]
+    fn migration_cursor(&self) -> SingleValueMapper<MigrationCursor<Self::Api>>;
+
+    /// The keys of `address_pair_map`, which a MapMapper keeps as a SetMapper under its
+    /// own storage key. Unlike the map, the set can resume an iteration.
+    #[storage_mapper("address_pair_map")]
+    fn address_pair_keys(&self) -> SetMapper<ManagedAddress>;
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storage_version")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,33 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const DEFAULT_LAUNCH_PROFILE: u32 = 0;
+
+#[multiversx_sc::module]
+pub trait LaunchProfileModule {
+    /// The default profile (id 0) always exists, other profiles are added by the owner
+    /// and selected by the creator on `newToken`. They are removed with
+    /// `removeLaunchProfile`, which lives in the contract as it also clears the settings
+    /// the other modules store per profile.
+    #[only_owner]
+    #[endpoint(addLaunchProfile)]
+    fn add_launch_profile(&self, profile_id: u32) {
+        require!(profile_id != DEFAULT_LAUNCH_PROFILE, "Default profile always exists");
+        require!(self.launch_profiles().insert(profile_id), "Profile already exists");
+    }
+
+    fn require_launch_profile(&self, profile_id: u32) {
+        require!(
+            profile_id == DEFAULT_LAUNCH_PROFILE || self.launch_profiles().contains(&profile_id),
+            "Launch profile does not exist"
+        );
+    }
+
+    #[view(getLaunchProfiles)]
+    #[storage_mapper("launch_profiles")]
+    fn launch_profiles(&self) -> UnorderedSetMapper<u32>;
+
+    #[view(getBondingLaunchProfile)]
+    #[storage_mapper("bonding_launch_profile")]
+    fn bonding_launch_profile(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,436 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::creator;
+use crate::factory;
+use crate::profile;
+use crate::stats;
+use crate::treasury;
+
+const SECONDS_PER_HOUR: u64 = 3_600;
+const VOLUME_WINDOW_HOURS: u64 = 24;
+const PROGRESS_PRECISION: u64 = 10_000;
+const DEFAULT_RANKING_SIZE: usize = 10;
+const MAX_RANKING_SIZE: usize = 50;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq)]
+pub enum RankingKind {
+    MarketCapProgress,
+    Volume,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct VolumeBucket<M: ManagedTypeApi> {
+    pub hour: u64,
+    pub volume: BigUint<M>,
+}
+
+/// Volume of the buckets still inside the 24h window as of `hour`, the oldest of which
+/// is the one of `first_hour`.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct VolumeWindow<M: ManagedTypeApi> {
+    pub hour: u64,
+    pub first_hour: u64,
+    pub total: BigUint<M>,
+}
+
+/// The lowest member of a ranking, so that a bonding trading outside a full ranking only
+/// has to be compared with it. A 24h volume drops when its oldest bucket leaves the window,
+/// from `refresh_hour` on one of the members may have and the lowest is looked up again.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct RankingLowest<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub score: BigUint<M>,
+    pub refresh_hour: u64,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi, Clone, ManagedVecItem)]
+pub struct RankingEntry<M: ManagedTypeApi> {
+    pub sc_address: ManagedAddress<M>,
+    pub token_id: TokenIdentifier<M>,
+    pub market_cap: BigUint<M>,
+    pub market_cap_progress: BigUint<M>,
+    pub volume_24h: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait RankingModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + profile::LaunchProfileModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+{
+    /// Called by a bonding after every swap with the swap volume (in the allowed token)
+    /// and its market cap after the swap.
+    #[endpoint(reportTrade)]
+    fn report_trade(&self, volume: BigUint, market_cap: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.check_is_pair_sc(&caller);
+
+        let hour = self.blockchain().get_block_timestamp() / SECONDS_PER_HOUR;
+        let mut window = self.get_volume_window(&caller, hour);
+        if window.total == 0 {
+            window.first_hour = hour;
+        }
+        let slot = hour % VOLUME_WINDOW_HOURS;
+        let mut bucket = self.get_volume_bucket(&caller, slot);
+        if bucket.hour != hour {
+            bucket.hour = hour;
+            bucket.volume = BigUint::zero();
+        }
+        bucket.volume += &volume;
+        window.total += &volume;
+        self.volume_bucket(&caller, slot).set(&bucket);
+        self.volume_window(&caller).set(&window);
+        self.last_market_cap(&caller).set(&market_cap);
+        self.on_volume_reported(&volume);
+        if self.is_graduated(&caller) {
+            return;
+        }
+
+        let progress = self.get_market_cap_progress(&caller);
+        self.cached_ranking_score(RankingKind::MarketCapProgress, &caller)
+            .set(&progress);
+        self.update_ranking(RankingKind::MarketCapProgress, &caller, &progress);
+        self.update_ranking(RankingKind::Volume, &caller, &window.total);
+    }
+
+    #[only_owner]
+    #[endpoint(setRankingSize)]
+    fn set_ranking_size(&self, ranking_size: usize) {
+        require!(
+            ranking_size > 0 && ranking_size <= MAX_RANKING_SIZE,
+            "Invalid ranking size"
+        );
+        self.ranking_size().set(ranking_size);
+        self.trim_ranking(RankingKind::MarketCapProgress, ranking_size);
+        self.trim_ranking(RankingKind::Volume, ranking_size);
+    }
+
+    /// Drops the lowest members of a ranking until it fits in `ranking_size`.
+    fn trim_ranking(&self, kind: RankingKind, ranking_size: usize) {
+        let mut ranking = self.ranking(kind);
+        if ranking.len() <= ranking_size {
+            return;
+        }
+
+        let current_hour = self.blockchain().get_block_timestamp() / SECONDS_PER_HOUR;
+        let mut addresses = ManagedVec::<Self::Api, ManagedAddress>::new();
+        let mut scores = ManagedVec::<Self::Api, BigUint>::new();
+        for address in ranking.iter() {
+            let (score, _) = self.get_member_score(kind, &address, current_hour);
+            scores.push(score);
+            addresses.push(address);
+        }
+
+        while ranking.len() > ranking_size {
+            let mut lowest = 0;
+            for i in 1..scores.len() {
+                if *scores.get(i) < *scores.get(lowest) {
+                    lowest = i;
+                }
+            }
+            ranking.swap_remove(&addresses.get(lowest));
+            addresses.remove(lowest);
+            scores.remove(lowest);
+        }
+        self.refresh_ranking_lowest(kind, current_hour);
+    }
+
+    /// A graduated bonding no longer trades on its curve, so it leaves both rankings.
+    fn remove_from_rankings(&self, bonding_address: &ManagedAddress) {
+        let current_hour = self.blockchain().get_block_timestamp() / SECONDS_PER_HOUR;
+        for kind in [RankingKind::MarketCapProgress, RankingKind::Volume] {
+            if self.ranking(kind).swap_remove(bonding_address) {
+                self.refresh_ranking_lowest(kind, current_hour);
+            }
+        }
+    }
+
+    fn get_ranking_size(&self) -> usize {
+        if self.ranking_size().is_empty() {
+            DEFAULT_RANKING_SIZE
+        } else {
+            self.ranking_size().get()
+        }
+    }
+
+    /// Evicts the lowest member when the ranking is full and the new score is higher. Only
+    /// the stored lowest member is read, unless the ranking changes or its lowest member
+    /// trades higher.
+    fn update_ranking(&self, kind: RankingKind, bonding_address: &ManagedAddress, score: &BigUint) {
+        let mut ranking = self.ranking(kind);
+        let current_hour = self.blockchain().get_block_timestamp() / SECONDS_PER_HOUR;
+        let lowest = self.get_ranking_lowest(kind, current_hour);
+        if ranking.contains(bonding_address) {
+            if let Some(mut lowest) = lowest {
+                if lowest.address == *bonding_address && *score > lowest.score {
+                    self.refresh_ranking_lowest(kind, current_hour);
+                    return;
+                }
+
+                if lowest.address == *bonding_address || *score < lowest.score {
+                    lowest.address = bonding_address.clone();
+                    lowest.score = score.clone();
+                }
+                let (_, expiry_hour) = self.get_member_score(kind, bonding_address, current_hour);
+                lowest.refresh_hour = lowest.refresh_hour.min(expiry_hour);
+                self.ranking_lowest(kind).set(lowest);
+            }
+            return;
+        }
+
+        if *score == 0 {
+            return;
+        }
+
+        if ranking.len() < self.get_ranking_size() {
+            ranking.insert(bonding_address.clone());
+            let (_, expiry_hour) = self.get_member_score(kind, bonding_address, current_hour);
+            let lowest = match lowest {
+                Some(lowest) if *score >= lowest.score => RankingLowest {
+                    refresh_hour: lowest.refresh_hour.min(expiry_hour),
+                    ..lowest
+                },
+                _ => RankingLowest {
+                    address: bonding_address.clone(),
+                    score: score.clone(),
+                    refresh_hour: expiry_hour,
+                },
+            };
+            self.ranking_lowest(kind).set(lowest);
+            return;
+        }
+
+        if let Some(lowest) = lowest {
+            if *score > lowest.score {
+                ranking.swap_remove(&lowest.address);
+                ranking.insert(bonding_address.clone());
+                self.refresh_ranking_lowest(kind, current_hour);
+            }
+        }
+    }
+
+    fn get_ranking_lowest(&self, kind: RankingKind, current_hour: u64) -> Option<RankingLowest<Self::Api>> {
+        let lowest_mapper = self.ranking_lowest(kind);
+        if lowest_mapper.is_empty() {
+            return None;
+        }
+
+        let lowest = lowest_mapper.get();
+        if current_hour >= lowest.refresh_hour {
+            return self.refresh_ranking_lowest(kind, current_hour);
+        }
+
+        Some(lowest)
+    }
+
+    fn refresh_ranking_lowest(&self, kind: RankingKind, current_hour: u64) -> Option<RankingLowest<Self::Api>> {
+        let mut lowest: Option<RankingLowest<Self::Api>> = None;
+        let mut refresh_hour = u64::MAX;
+        for address in self.ranking(kind).iter() {
+            let (score, expiry_hour) = self.get_member_score(kind, &address, current_hour);
+            refresh_hour = refresh_hour.min(expiry_hour);
+            if lowest.as_ref().map_or(true, |lowest| score < lowest.score) {
+                lowest = Some(RankingLowest {
+                    address,
+                    score,
+                    refresh_hour: 0,
+                });
+            }
+        }
+
+        match lowest {
+            Some(mut lowest) => {
+                lowest.refresh_hour = refresh_hour;
+                self.ranking_lowest(kind).set(&lowest);
+                Some(lowest)
+            },
+            None => {
+                self.ranking_lowest(kind).clear();
+                None
+            },
+        }
+    }
+
+    /// The score of a member as of `current_hour`, with the hour from which it may be
+    /// lower. Market cap progress only moves when the bonding trades. The 24h volume stored
+    /// at the last trade holds until its oldest bucket leaves the window, so it is a single
+    /// read per member until then.
+    fn get_member_score(
+        &self,
+        kind: RankingKind,
+        bonding_address: &ManagedAddress,
+        current_hour: u64,
+    ) -> (BigUint, u64) {
+        if kind == RankingKind::Volume {
+            let mut window = self.volume_window(bonding_address).get();
+            if window.first_hour + VOLUME_WINDOW_HOURS <= current_hour {
+                window = self.get_volume_window(bonding_address, current_hour);
+            }
+            if window.total == 0 {
+                return (BigUint::zero(), u64::MAX);
+            }
+            return (window.total, window.first_hour + VOLUME_WINDOW_HOURS);
+        }
+
+        (self.cached_ranking_score(kind, bonding_address).get(), u64::MAX)
+    }
+
+    fn ranking_score(&self, kind: RankingKind, bonding_address: &ManagedAddress) -> BigUint {
+        match kind {
+            RankingKind::MarketCapProgress => self.get_market_cap_progress(bonding_address),
+            RankingKind::Volume => self.get_volume_24h(bonding_address),
+        }
+    }
+
+    /// Relative to the max market cap the bonding runs with, not the factory one.
+    fn get_market_cap_progress(&self, bonding_address: &ManagedAddress) -> BigUint {
+        let max_market_cap = self.get_bonding_params(bonding_address.clone()).max_market_cap;
+        if max_market_cap == 0 {
+            return BigUint::zero();
+        }
+
+        self.last_market_cap(bonding_address).get() * PROGRESS_PRECISION / max_market_cap
+    }
+
+    fn get_volume_24h(&self, bonding_address: &ManagedAddress) -> BigUint {
+        let current_hour = self.blockchain().get_block_timestamp() / SECONDS_PER_HOUR;
+        self.get_volume_window(bonding_address, current_hour).total
+    }
+
+    /// Moves the stored window to `current_hour`, taking out the buckets that left it
+    /// since. The counted buckets all lie between `first_hour` and the hour of the stored
+    /// window, so that is at most VOLUME_WINDOW_HOURS reads whatever the gap.
+    fn get_volume_window(&self, bonding_address: &ManagedAddress, current_hour: u64) -> VolumeWindow<Self::Api> {
+        let window_mapper = self.volume_window(bonding_address);
+        if window_mapper.is_empty() {
+            return VolumeWindow {
+                hour: current_hour,
+                first_hour: current_hour,
+                total: BigUint::zero(),
+            };
+        }
+
+        let mut window = window_mapper.get();
+        if window.hour >= current_hour {
+            return window;
+        }
+
+        let last_hour = window.hour;
+        window.hour = current_hour;
+        if window.total == 0 || window.first_hour + VOLUME_WINDOW_HOURS > current_hour {
+            return window;
+        }
+
+        // a bucket leaves the window once its hour is more than 23 hours old
+        let mut first_counted = current_hour;
+        for hour in window.first_hour..=last_hour {
+            let bucket = self.get_volume_bucket(bonding_address, hour % VOLUME_WINDOW_HOURS);
+            if bucket.hour != hour {
+                continue;
+            }
+            if hour + VOLUME_WINDOW_HOURS > current_hour {
+                first_counted = hour;
+                break;
+            }
+            window.total -= bucket.volume;
+        }
+        window.first_hour = first_counted;
+        window
+    }
+
+    #[view(getTopByMarketCapProgress)]
+    fn get_top_by_market_cap_progress(&self) -> MultiValueEncoded<RankingEntry<Self::Api>> {
+        self.get_sorted_ranking(RankingKind::MarketCapProgress)
+    }
+
+    #[view(getTopByVolume)]
+    fn get_top_by_volume(&self) -> MultiValueEncoded<RankingEntry<Self::Api>> {
+        self.get_sorted_ranking(RankingKind::Volume)
+    }
+
+    fn get_sorted_ranking(&self, kind: RankingKind) -> MultiValueEncoded<RankingEntry<Self::Api>> {
+        let mut entries = ManagedVec::<Self::Api, RankingEntry<Self::Api>>::new();
+        let mut scores = ManagedVec::<Self::Api, BigUint>::new();
+        for address in self.ranking(kind).iter() {
+            let score = self.ranking_score(kind, &address);
+            if score == 0 {
+                continue;
+            }
+
+            entries.push(RankingEntry {
+                token_id: self.address_pair_map().get(&address).unwrap().first_token_id,
+                market_cap: self.last_market_cap(&address).get(),
+                market_cap_progress: self.get_market_cap_progress(&address),
+                volume_24h: self.get_volume_24h(&address),
+                sc_address: address,
+            });
+            scores.push(score);
+        }
+
+        // the rankings are bounded by MAX_RANKING_SIZE, a simple insertion sort is enough
+        let len = entries.len();
+        for i in 1..len {
+            let mut j = i;
+            while j > 0 && *scores.get(j - 1) < *scores.get(j) {
+                let previous_entry = entries.get(j - 1).clone();
+                let current_entry = entries.get(j).clone();
+                let _ = entries.set(j - 1, &current_entry);
+                let _ = entries.set(j, &previous_entry);
+
+                let previous_score = scores.get(j - 1).clone_value();
+                let current_score = scores.get(j).clone_value();
+                let _ = scores.set(j - 1, &current_score);
+                let _ = scores.set(j, &previous_score);
+
+                j -= 1;
+            }
+        }
+
+        entries.into()
+    }
+
+    fn get_volume_bucket(&self, bonding_address: &ManagedAddress, slot: u64) -> VolumeBucket<Self::Api> {
+        let mapper = self.volume_bucket(bonding_address, slot);
+        if mapper.is_empty() {
+            VolumeBucket {
+                hour: 0,
+                volume: BigUint::zero(),
+            }
+        } else {
+            mapper.get()
+        }
+    }
+
+    #[view(getVolume24h)]
+    fn get_volume_24h_view(&self, bonding_address: ManagedAddress) -> BigUint {
+        self.get_volume_24h(&bonding_address)
+    }
+
+    #[view(getLastMarketCap)]
+    #[storage_mapper("last_market_cap")]
+    fn last_market_cap(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("volume_bucket")]
+    fn volume_bucket(&self, bonding_address: &ManagedAddress, slot: u64) -> SingleValueMapper<VolumeBucket<Self::Api>>;
+
+    #[storage_mapper("volume_window")]
+    fn volume_window(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<VolumeWindow<Self::Api>>;
+
+    #[storage_mapper("cached_ranking_score")]
+    fn cached_ranking_score(&self, kind: RankingKind, bonding_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("ranking")]
+    fn ranking(&self, kind: RankingKind) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("ranking_lowest")]
+    fn ranking_lowest(&self, kind: RankingKind) -> SingleValueMapper<RankingLowest<Self::Api>>;
+
+    #[view(getRankingSize)]
+    #[storage_mapper("ranking_size")]
+    fn ranking_size(&self) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,154 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct LaunchCounter {
+    pub start: u64,
+    pub count: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct LaunchQuota {
+    pub is_exempt: bool,
+    pub max_launches_per_window: u64,
+    pub remaining_in_window: u64,
+    pub window_end_round: u64,
+    pub max_launches_per_block: u64,
+    pub remaining_in_block: u64,
+}
+
+#[multiversx_sc::module]
+pub trait RateLimitModule {
+    /// A limit of 0 disables the corresponding check.
+    #[only_owner]
+    #[endpoint(setLaunchRateLimits)]
+    fn set_launch_rate_limits(
+        &self,
+        launch_window_rounds: u64,
+        max_launches_per_window: u64,
+        max_launches_per_block: u64,
+    ) {
+        require!(
+            max_launches_per_window == 0 || launch_window_rounds > 0,
+            "Launch window cannot be zero"
+        );
+        self.launch_window_rounds().set(launch_window_rounds);
+        self.max_launches_per_window().set(max_launches_per_window);
+        self.max_launches_per_block().set(max_launches_per_block);
+    }
+
+    #[only_owner]
+    #[endpoint(addRateLimitExempt)]
+    fn add_rate_limit_exempt(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        for address in addresses {
+            self.rate_limit_exempt().insert(address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeRateLimitExempt)]
+    fn remove_rate_limit_exempt(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        for address in addresses {
+            self.rate_limit_exempt().swap_remove(&address);
+        }
+    }
+
+    fn check_and_record_launch(&self, caller: &ManagedAddress) {
+        if self.rate_limit_exempt().contains(caller) {
+            return;
+        }
+
+        let max_launches_per_block = self.max_launches_per_block().get();
+        if max_launches_per_block > 0 {
+            let mut counter = self.get_block_launches();
+            require!(
+                counter.count < max_launches_per_block,
+                "Launch limit for this block reached, try again in the next block"
+            );
+            counter.count += 1;
+            self.block_launches().set(&counter);
+        }
+
+        let max_launches_per_window = self.max_launches_per_window().get();
+        if max_launches_per_window > 0 {
+            let mut counter = self.get_address_launches(caller);
+            require!(
+                counter.count < max_launches_per_window,
+                "Launch limit for this address reached, try again later"
+            );
+            counter.count += 1;
+            self.address_launches(caller).set(&counter);
+        }
+    }
+
+    fn get_block_launches(&self) -> LaunchCounter {
+        let block_nonce = self.blockchain().get_block_nonce();
+        let mapper = self.block_launches();
+        if !mapper.is_empty() {
+            let counter = mapper.get();
+            if counter.start == block_nonce {
+                return counter;
+            }
+        }
+
+        LaunchCounter {
+            start: block_nonce,
+            count: 0,
+        }
+    }
+
+    fn get_address_launches(&self, address: &ManagedAddress) -> LaunchCounter {
+        let current_round = self.blockchain().get_block_round();
+        let mapper = self.address_launches(address);
+        if !mapper.is_empty() {
+            let counter = mapper.get();
+            if current_round < counter.start + self.launch_window_rounds().get() {
+                return counter;
+            }
+        }
+
+        LaunchCounter {
+            start: current_round,
+            count: 0,
+        }
+    }
+
+    #[view(getRemainingLaunchQuota)]
+    fn get_remaining_launch_quota(&self, address: ManagedAddress) -> LaunchQuota {
+        let max_launches_per_window = self.max_launches_per_window().get();
+        let max_launches_per_block = self.max_launches_per_block().get();
+        let address_launches = self.get_address_launches(&address);
+        let block_launches = self.get_block_launches();
+
+        LaunchQuota {
+            is_exempt: self.rate_limit_exempt().contains(&address),
+            max_launches_per_window,
+            remaining_in_window: max_launches_per_window.saturating_sub(address_launches.count),
+            window_end_round: address_launches.start + self.launch_window_rounds().get(),
+            max_launches_per_block,
+            remaining_in_block: max_launches_per_block.saturating_sub(block_launches.count),
+        }
+    }
+
+    #[view(getLaunchWindowRounds)]
+    #[storage_mapper("launch_window_rounds")]
+    fn launch_window_rounds(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxLaunchesPerWindow)]
+    #[storage_mapper("max_launches_per_window")]
+    fn max_launches_per_window(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxLaunchesPerBlock)]
+    #[storage_mapper("max_launches_per_block")]
+    fn max_launches_per_block(&self) -> SingleValueMapper<u64>;
+
+    #[view(getRateLimitExempt)]
+    #[storage_mapper("rate_limit_exempt")]
+    fn rate_limit_exempt(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("address_launches")]
+    fn address_launches(&self, address: &ManagedAddress) -> SingleValueMapper<LaunchCounter>;
+
+    #[storage_mapper("block_launches")]
+    fn block_launches(&self) -> SingleValueMapper<LaunchCounter>;
+}
@@ -0,0 +1,198 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::allocation;
+use crate::bonding_launch_proxy::ProxyTrait as _;
+use crate::bonding_proxy::ProxyTrait as _;
+use crate::config::{self, DEAD_ADDRESS};
+use crate::creator;
+use crate::creator_fees::MAX_PERCENT;
+use crate::factory;
+use crate::profile;
+use crate::stats;
+use crate::treasury::{self, FeeSource};
+use crate::vesting;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct UpcomingLaunch<M: ManagedTypeApi> {
+    pub sc_address: ManagedAddress<M>,
+    pub token_id: TokenIdentifier<M>,
+    pub creator: ManagedAddress<M>,
+    pub start_timestamp: u64,
+}
+
+#[multiversx_sc::module]
+pub trait ScheduleModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+    + profile::LaunchProfileModule
+    + vesting::VestingModule
+    + allocation::AllocationModule
+{
+    /// A max start delay of 0 disables scheduled launches.
+    #[only_owner]
+    #[endpoint(setMaxStartDelay)]
+    fn set_max_start_delay(&self, max_start_delay: u64) {
+        self.max_start_delay().set(max_start_delay);
+    }
+
+    /// Share of the launch fee given back to a creator cancelling a scheduled launch, in basis points.
+    #[only_owner]
+    #[endpoint(setCancelRefundPercent)]
+    fn set_cancel_refund_percent(&self, cancel_refund_percent: u64) {
+        require!(cancel_refund_percent <= MAX_PERCENT, "Cancel refund percent too high");
+        self.cancel_refund_percent().set(cancel_refund_percent);
+    }
+
+    fn validate_start_timestamp(&self, start_timestamp: u64) {
+        if start_timestamp == 0 {
+            return;
+        }
+
+        let max_start_delay = self.max_start_delay().get();
+        require!(max_start_delay > 0, "Scheduled launches are disabled");
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(start_timestamp > current_timestamp, "Start timestamp must be in the future");
+        require!(
+            start_timestamp <= current_timestamp + max_start_delay,
+            "Start timestamp is too far in the future"
+        );
+    }
+
+    fn is_scheduled_start(&self, start_timestamp: u64) -> bool {
+        start_timestamp > self.blockchain().get_block_timestamp()
+    }
+
+    /// Records a launch whose trading starts later. The launch fee stays in escrow until
+    /// trading starts, so that part of it can be refunded if the creator cancels.
+    fn schedule_launch(&self, bonding_address: &ManagedAddress, start_timestamp: u64, launch_fee: &BigUint) {
+        self.launch_start_timestamp(bonding_address).set(start_timestamp);
+        self.launch_fee_escrow(bonding_address).set(launch_fee);
+        self.add_owed(&EgldOrEsdtTokenIdentifier::egld(), launch_fee);
+        self.scheduled_launches().insert(bonding_address.clone());
+    }
+
+    #[endpoint(releaseLaunchFee)]
+    fn release_launch_fee(&self, token_id: TokenIdentifier) {
+        let bonding_address = self.require_launched_token(&token_id);
+        require!(
+            self.scheduled_launches().contains(&bonding_address),
+            "Launch is not scheduled"
+        );
+        require!(
+            !self.is_scheduled_start(self.launch_start_timestamp(&bonding_address).get()),
+            "Launch has not started yet"
+        );
+
+        self.scheduled_launches().swap_remove(&bonding_address);
+        let launch_fee = self.launch_fee_escrow(&bonding_address).take();
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.remove_owed(&egld, &launch_fee);
+        self.accrue_fees(FeeSource::ScheduledLaunchFee, &egld, &launch_fee);
+    }
+
+    #[endpoint(cancelScheduledLaunch)]
+    fn cancel_scheduled_launch(&self, token_id: TokenIdentifier) {
+        let bonding_address = self.require_token_creator(&token_id);
+        require!(
+            self.scheduled_launches().contains(&bonding_address),
+            "Launch is not scheduled"
+        );
+        require!(
+            self.is_scheduled_start(self.launch_start_timestamp(&bonding_address).get()),
+            "Launch has already started"
+        );
+
+        self.scheduled_launches().swap_remove(&bonding_address);
+        self.cancelled_launch(&bonding_address).set(true);
+        self.on_bonding_paused(&bonding_address);
+        let _: IgnoreValue = self
+            .bonding_contract_proxy(bonding_address.clone())
+            .pause()
+            .execute_on_dest_context();
+        self.burn_cancelled_supply(&token_id, &bonding_address);
+
+        let launch_fee = self.launch_fee_escrow(&bonding_address).take();
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.remove_owed(&egld, &launch_fee);
+        let refund = &launch_fee * self.cancel_refund_percent().get() / MAX_PERCENT;
+        if refund > 0 {
+            self.send().direct_egld(&self.blockchain().get_caller(), &refund);
+            self.on_refund(&refund);
+        }
+
+        let remaining_fee = launch_fee - refund;
+        self.accrue_fees(FeeSource::CancelledLaunchFee, &egld, &remaining_fee);
+    }
+
+    /// The curve supply and the DEX reserve of a cancelled launch can never be traded, so
+    /// they are sent to DEAD_ADDRESS. The airdrop reserve and vested allocations are left to
+    /// their owners.
+    fn burn_cancelled_supply(&self, token_id: &TokenIdentifier, bonding_address: &ManagedAddress) {
+        let mut burned_amount: BigUint = self
+            .bonding_launch_proxy(bonding_address.clone())
+            .withdraw_token_reserve()
+            .execute_on_dest_context();
+
+        let dex_reserve = self.dex_liquidity_reserve(token_id).take();
+        if dex_reserve > 0 {
+            self.remove_owed_esdt(token_id, &dex_reserve);
+            burned_amount += dex_reserve;
+        }
+
+        if burned_amount > 0 {
+            self.send()
+                .direct_esdt(&ManagedAddress::from(&DEAD_ADDRESS), token_id, 0, &burned_amount);
+        }
+        self.cancelled_launch_burned_supply(bonding_address).set(&burned_amount);
+    }
+
+    #[view(getUpcomingLaunches)]
+    fn get_upcoming_launches(&self) -> MultiValueEncoded<UpcomingLaunch<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for bonding_address in self.scheduled_launches().iter() {
+            let start_timestamp = self.launch_start_timestamp(&bonding_address).get();
+            if !self.is_scheduled_start(start_timestamp) {
+                continue;
+            }
+
+            result.push(UpcomingLaunch {
+                token_id: self.address_pair_map().get(&bonding_address).unwrap().first_token_id,
+                creator: self.bonding_creator(&bonding_address).get(),
+                sc_address: bonding_address,
+                start_timestamp,
+            });
+        }
+        result
+    }
+
+    #[view(getMaxStartDelay)]
+    #[storage_mapper("max_start_delay")]
+    fn max_start_delay(&self) -> SingleValueMapper<u64>;
+
+    #[view(getCancelRefundPercent)]
+    #[storage_mapper("cancel_refund_percent")]
+    fn cancel_refund_percent(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLaunchStartTimestamp)]
+    #[storage_mapper("launch_start_timestamp")]
+    fn launch_start_timestamp(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(isLaunchCancelled)]
+    #[storage_mapper("cancelled_launch")]
+    fn cancelled_launch(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getCancelledLaunchBurnedSupply)]
+    #[storage_mapper("cancelled_launch_burned_supply")]
+    fn cancelled_launch_burned_supply(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("launch_fee_escrow")]
+    fn launch_fee_escrow(&self, bonding_address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("scheduled_launches")]
+    fn scheduled_launches(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
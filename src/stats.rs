@@ -0,0 +1,91 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct PlatformStats<M: ManagedTypeApi> {
+    pub total_launches: u64,
+    pub active_bondings: u64,
+    pub paused_bondings: u64,
+    pub graduated_bondings: u64,
+    pub total_launch_fees: BigUint<M>,
+    pub total_fees_forwarded: BigUint<M>,
+    pub total_refunds: BigUint<M>,
+    pub total_volume: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait StatsModule {
+    fn on_launch(&self, launch_fee: &BigUint) {
+        self.total_launches().update(|total| *total += 1);
+        self.total_launch_fees().update(|total| *total += launch_fee);
+    }
+
+    fn on_fees_forwarded(&self, amount: &BigUint) {
+        self.total_fees_forwarded().update(|total| *total += amount);
+    }
+
+    fn on_refund(&self, amount: &BigUint) {
+        self.total_refunds().update(|total| *total += amount);
+    }
+
+    fn on_volume_reported(&self, volume: &BigUint) {
+        self.total_volume().update(|total| *total += volume);
+    }
+
+    /// A bonding is counted as either paused or graduated, never both, so callers must not
+    /// report graduated bondings as paused.
+    fn on_bonding_paused(&self, bonding_address: &ManagedAddress) {
+        self.paused_bondings().insert(bonding_address.clone());
+    }
+
+    fn on_bonding_resumed(&self, bonding_address: &ManagedAddress) {
+        self.paused_bondings().swap_remove(bonding_address);
+    }
+
+    fn on_bonding_graduated(&self, bonding_address: &ManagedAddress) {
+        self.paused_bondings().swap_remove(bonding_address);
+        self.graduated_bondings_count().update(|count| *count += 1);
+    }
+
+    #[view(getPlatformStats)]
+    fn get_platform_stats(&self) -> PlatformStats<Self::Api> {
+        let total_launches = self.total_launches().get();
+        let paused_bondings = self.paused_bondings().len() as u64;
+        let graduated_bondings = self.graduated_bondings_count().get();
+
+        // bondings paused before the launch migration ran may not be counted in the total yet
+        PlatformStats {
+            total_launches,
+            active_bondings: total_launches
+                .saturating_sub(paused_bondings)
+                .saturating_sub(graduated_bondings),
+            paused_bondings,
+            graduated_bondings,
+            total_launch_fees: self.total_launch_fees().get(),
+            total_fees_forwarded: self.total_fees_forwarded().get(),
+            total_refunds: self.total_refunds().get(),
+            total_volume: self.total_volume().get(),
+        }
+    }
+
+    #[storage_mapper("stats_total_launches")]
+    fn total_launches(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("stats_paused_bondings")]
+    fn paused_bondings(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("stats_graduated_bondings")]
+    fn graduated_bondings_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("stats_total_launch_fees")]
+    fn total_launch_fees(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("stats_total_fees_forwarded")]
+    fn total_fees_forwarded(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("stats_total_refunds")]
+    fn total_refunds(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("stats_total_volume")]
+    fn total_volume(&self) -> SingleValueMapper<BigUint>;
+}
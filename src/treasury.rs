@@ -0,0 +1,205 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::stats;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq)]
+pub enum FeeSource {
+    LaunchFee,
+    ScheduledLaunchFee,
+    CancelledLaunchFee,
+    SwapFees,
+    GraduationLp,
+}
+
+const FEE_SOURCES: [FeeSource; 5] = [
+    FeeSource::LaunchFee,
+    FeeSource::ScheduledLaunchFee,
+    FeeSource::CancelledLaunchFee,
+    FeeSource::SwapFees,
+    FeeSource::GraduationLp,
+];
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct FeeAccount<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub accrued: BigUint<M>,
+    pub withdrawn: BigUint<M>,
+    pub withdrawable: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait TreasuryModule: config::ConfigModule + stats::StatsModule {
+    /// In pull mode fees stay in the master until `withdrawFees` is called, otherwise they
+    /// are sent to the fees collector as soon as they are earned.
+    #[only_owner]
+    #[endpoint(setFeesPullMode)]
+    fn set_fees_pull_mode(&self, pull_mode: bool) {
+        self.fees_pull_mode().set(pull_mode);
+    }
+
+    fn accrue_fees(&self, source: FeeSource, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if *amount == 0 {
+            return;
+        }
+
+        self.fee_tokens().insert(token_id.clone());
+        self.fees_accrued(source, token_id).update(|accrued| *accrued += amount);
+        if self.fees_pull_mode().get() {
+            self.withdrawable_fees(token_id).update(|withdrawable| *withdrawable += amount);
+        } else {
+            self.send_fees(token_id, amount);
+        }
+    }
+
+    fn send_fees(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.fees_withdrawn(token_id).update(|withdrawn| *withdrawn += amount);
+        self.send().direct(&self.fees_collector().get(), token_id, 0, amount);
+        if token_id.is_egld() {
+            self.on_fees_forwarded(amount);
+        }
+    }
+
+    /// Sends the fees held in pull mode to the fees collector, for the given tokens or for
+    /// all of them.
+    #[endpoint(withdrawFees)]
+    fn withdraw_fees(
+        &self,
+        token_ids: MultiValueEncoded<EgldOrEsdtTokenIdentifier>,
+    ) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address() || caller == self.fees_collector().get(),
+            "Only the owner or the fees collector can withdraw fees"
+        );
+
+        let mut tokens = token_ids;
+        if tokens.is_empty() {
+            for token_id in self.fee_tokens().iter() {
+                tokens.push(token_id);
+            }
+        }
+
+        let mut payments = MultiValueEncoded::new();
+        for token_id in tokens {
+            let amount = self.withdrawable_fees(&token_id).take();
+            if amount == 0 {
+                continue;
+            }
+
+            self.send_fees(&token_id, &amount);
+            payments.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+        }
+
+        require!(!payments.is_empty(), "No fees to withdraw");
+        payments
+    }
+
+    /// Funds held for someone else: pending issuances, escrowed launch fees, creator fees,
+    /// vesting, airdrops, supply reserves and locked LP. They are never recoverable.
+    fn add_owed(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if *amount == 0 {
+            return;
+        }
+
+        self.owed_tokens().insert(token_id.clone());
+        self.owed_balance(token_id).update(|owed| *owed += amount);
+    }
+
+    /// Saturates at zero, so that funds received before the ledger existed can still be paid out.
+    fn remove_owed(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.owed_balance(token_id).update(|owed| {
+            if *owed > *amount {
+                *owed -= amount;
+            } else {
+                *owed = BigUint::zero();
+            }
+        });
+    }
+
+    fn add_owed_esdt(&self, token_id: &TokenIdentifier, amount: &BigUint) {
+        self.add_owed(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), amount);
+    }
+
+    fn remove_owed_esdt(&self, token_id: &TokenIdentifier, amount: &BigUint) {
+        self.remove_owed(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), amount);
+    }
+
+    /// Sends the part of the master balance that is neither owed to anyone nor fees waiting
+    /// for withdrawal, e.g. failed refunds or tokens sent by mistake.
+    #[only_owner]
+    #[endpoint(recoverStrayFunds)]
+    fn recover_stray_funds(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        opt_to: OptionalValue<ManagedAddress>,
+    ) -> BigUint {
+        let amount = self.get_stray_balance(token_id.clone());
+        require!(amount > 0, "No stray funds for this token");
+
+        let to = opt_to
+            .into_option()
+            .unwrap_or_else(|| self.blockchain().get_caller());
+        self.send().direct(&to, &token_id, 0, &amount);
+        amount
+    }
+
+    #[view(getStrayBalance)]
+    fn get_stray_balance(&self, token_id: EgldOrEsdtTokenIdentifier) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(&token_id, 0);
+        let held = self.owed_balance(&token_id).get() + self.withdrawable_fees(&token_id).get();
+        if balance > held {
+            balance - held
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    #[view(getFeeAccounts)]
+    fn get_fee_accounts(&self) -> MultiValueEncoded<FeeAccount<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for token_id in self.fee_tokens().iter() {
+            let mut accrued = BigUint::zero();
+            for source in FEE_SOURCES {
+                accrued += self.fees_accrued(source, &token_id).get();
+            }
+
+            result.push(FeeAccount {
+                withdrawn: self.fees_withdrawn(&token_id).get(),
+                withdrawable: self.withdrawable_fees(&token_id).get(),
+                token_id,
+                accrued,
+            });
+        }
+        result
+    }
+
+    #[view(isFeesPullMode)]
+    #[storage_mapper("fees_pull_mode")]
+    fn fees_pull_mode(&self) -> SingleValueMapper<bool>;
+
+    #[view(getFeeTokens)]
+    #[storage_mapper("fee_tokens")]
+    fn fee_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getFeesAccrued)]
+    #[storage_mapper("fees_accrued")]
+    fn fees_accrued(&self, source: FeeSource, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getFeesWithdrawn)]
+    #[storage_mapper("fees_withdrawn")]
+    fn fees_withdrawn(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getWithdrawableFees)]
+    #[storage_mapper("withdrawable_fees")]
+    fn withdrawable_fees(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getOwedTokens)]
+    #[storage_mapper("owed_tokens")]
+    fn owed_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getOwedBalance)]
+    #[storage_mapper("owed_balance")]
+    fn owed_balance(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,194 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config;
+use crate::creator;
+use crate::factory;
+use crate::profile;
+use crate::stats;
+use crate::treasury;
+
+/// Ten years, longer than any lock needs, and short enough that a lock end never overflows.
+const MAX_LOCK_DURATION: u64 = 10 * 365 * 24 * 3_600;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, ManagedVecItem)]
+pub struct VestingSchedule<M: ManagedTypeApi> {
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub start_timestamp: u64,
+    pub cliff_duration: u64,
+    pub vesting_duration: u64,
+}
+
+/// Lock applied to the tokens a creator buys at launch.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy)]
+pub struct CreatorBuyVesting {
+    pub cliff_duration: u64,
+    pub vesting_duration: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct VestingAmounts<M: ManagedTypeApi> {
+    pub locked: BigUint<M>,
+    pub claimable: BigUint<M>,
+    pub claimed: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait VestingModule:
+    config::ConfigModule
+    + factory::FactoryModule
+    + creator::CreatorModule
+    + profile::LaunchProfileModule
+    + stats::StatsModule
+    + treasury::TreasuryModule
+{
+    /// Launches with the profile get their creator buy sent to the master and vested for
+    /// the creator instead.
+    #[only_owner]
+    #[endpoint(setProfileCreatorBuyVesting)]
+    fn set_profile_creator_buy_vesting(&self, profile_id: u32, cliff_duration: u64, vesting_duration: u64) {
+        self.require_launch_profile(profile_id);
+        self.validate_lock_durations(cliff_duration, vesting_duration);
+
+        self.profile_creator_buy_vesting(profile_id).set(CreatorBuyVesting {
+            cliff_duration,
+            vesting_duration,
+        });
+    }
+
+    #[only_owner]
+    #[endpoint(clearProfileCreatorBuyVesting)]
+    fn clear_profile_creator_buy_vesting(&self, profile_id: u32) {
+        self.profile_creator_buy_vesting(profile_id).clear();
+    }
+
+    fn get_profile_creator_buy_vesting(&self, profile_id: u32) -> Option<CreatorBuyVesting> {
+        let profile_mapper = self.profile_creator_buy_vesting(profile_id);
+        if profile_mapper.is_empty() {
+            return None;
+        }
+
+        Some(profile_mapper.get())
+    }
+
+    /// Lets the creator voluntarily lock tokens of their own launch, e.g. the ones bought at creation.
+    #[payable("*")]
+    #[endpoint(lockCreatorTokens)]
+    fn lock_creator_tokens(&self, cliff_duration: u64, vesting_duration: u64) {
+        let (token_id, amount) = self.call_value().single_fungible_esdt();
+        self.require_token_creator(&token_id);
+        require!(amount > 0, "No tokens sent");
+        self.validate_lock_durations(cliff_duration, vesting_duration);
+
+        self.add_vesting_schedule(
+            &token_id,
+            &amount,
+            self.blockchain().get_block_timestamp(),
+            cliff_duration,
+            vesting_duration,
+        );
+    }
+
+    #[endpoint(claimVestedTokens)]
+    fn claim_vested_tokens(&self, token_id: TokenIdentifier) -> BigUint {
+        self.require_token_creator(&token_id);
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut schedules = self.vesting_schedules(&token_id);
+        let mut total_claimable = BigUint::zero();
+        for index in 1..=schedules.len() {
+            let mut schedule = schedules.get(index);
+            let claimable = self.get_vested_amount(&schedule, current_timestamp) - &schedule.claimed_amount;
+            if claimable == 0 {
+                continue;
+            }
+
+            schedule.claimed_amount += &claimable;
+            schedules.set(index, &schedule);
+            total_claimable += claimable;
+        }
+
+        require!(total_claimable > 0, "Nothing to claim");
+        self.remove_owed_esdt(&token_id, &total_claimable);
+        self.send()
+            .direct_esdt(&self.blockchain().get_caller(), &token_id, 0, &total_claimable);
+        total_claimable
+    }
+
+    fn validate_lock_durations(&self, cliff_duration: u64, vesting_duration: u64) {
+        require!(
+            cliff_duration > 0 || vesting_duration > 0,
+            "Lock duration cannot be zero"
+        );
+        require!(
+            cliff_duration <= MAX_LOCK_DURATION && vesting_duration <= MAX_LOCK_DURATION,
+            "Lock duration too long"
+        );
+    }
+
+    fn add_vesting_schedule(
+        &self,
+        token_id: &TokenIdentifier,
+        amount: &BigUint,
+        start_timestamp: u64,
+        cliff_duration: u64,
+        vesting_duration: u64,
+    ) {
+        self.vesting_schedules(token_id).push(&VestingSchedule {
+            total_amount: amount.clone(),
+            claimed_amount: BigUint::zero(),
+            start_timestamp,
+            cliff_duration,
+            vesting_duration,
+        });
+        self.add_owed_esdt(token_id, amount);
+    }
+
+    fn get_vested_amount(&self, schedule: &VestingSchedule<Self::Api>, timestamp: u64) -> BigUint {
+        let cliff_end = schedule.start_timestamp.saturating_add(schedule.cliff_duration);
+        if timestamp < cliff_end {
+            return BigUint::zero();
+        }
+
+        let elapsed = timestamp - cliff_end;
+        if elapsed >= schedule.vesting_duration {
+            return schedule.total_amount.clone();
+        }
+
+        &schedule.total_amount * elapsed / schedule.vesting_duration
+    }
+
+    #[view(getVestingSchedules)]
+    fn get_vesting_schedules(&self, token_id: TokenIdentifier) -> MultiValueEncoded<VestingSchedule<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for schedule in self.vesting_schedules(&token_id).iter() {
+            result.push(schedule);
+        }
+        result
+    }
+
+    #[view(getVestingAmounts)]
+    fn get_vesting_amounts(&self, token_id: TokenIdentifier) -> VestingAmounts<Self::Api> {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut amounts = VestingAmounts {
+            locked: BigUint::zero(),
+            claimable: BigUint::zero(),
+            claimed: BigUint::zero(),
+        };
+        for schedule in self.vesting_schedules(&token_id).iter() {
+            let vested = self.get_vested_amount(&schedule, current_timestamp);
+            amounts.locked += &schedule.total_amount - &vested;
+            amounts.claimable += &vested - &schedule.claimed_amount;
+            amounts.claimed += &schedule.claimed_amount;
+        }
+        amounts
+    }
+
+    #[view(getProfileCreatorBuyVesting)]
+    #[storage_mapper("profile_creator_buy_vesting")]
+    fn profile_creator_buy_vesting(&self, profile_id: u32) -> SingleValueMapper<CreatorBuyVesting>;
+
+    #[storage_mapper("vesting_schedules")]
+    fn vesting_schedules(&self, token_id: &TokenIdentifier) -> VecMapper<VestingSchedule<Self::Api>>;
+}
@@ -0,0 +1,484 @@
+mod master_setup;
+
+use master_setup::*;
+use multiversx_sc::storage::mappers::StorageClearable;
+use multiversx_sc::types::{Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_token_id, rust_biguint, DebugApi};
+
+use master::config::ConfigModule;
+use master::migration::{MigrationModule, CURRENT_STORAGE_VERSION};
+use master::stats::StatsModule;
+use master::treasury::TreasuryModule;
+use master::MasterContract;
+
+const FUZZ_SEEDS: u64 = 16;
+const FUZZ_STEPS: usize = 80;
+const FUZZ_CREATORS: usize = 4;
+
+/// The setup over the plain contract builders, so that the steps need no generic bounds.
+type FuzzSetup = MasterSetup<
+    fn() -> master::ContractObj<DebugApi>,
+    fn() -> bonding_mock::ContractObj<DebugApi>,
+    fn() -> router_mock::ContractObj<DebugApi>,
+    fn() -> oracle_mock::ContractObj<DebugApi>,
+>;
+
+/// xorshift64*, so that every failing run can be replayed from its seed without extra dependencies.
+struct FuzzRng(u64);
+
+impl FuzzRng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+struct PendingIssuance {
+    creator: usize,
+    launch_fee: u64,
+    issue: PendingIssue,
+    /// Started by the master an upgrade replaced, which recorded neither the callback
+    /// request nor what it owed.
+    legacy: bool,
+}
+
+/// What the test expects the chain to look like, built only from the calls the test made.
+struct FuzzState {
+    creators: Vec<Address>,
+    creator_balances: Vec<u64>,
+    pending: Vec<PendingIssuance>,
+    launched_tokens: Vec<Vec<u8>>,
+    paused_bondings: Vec<Address>,
+    new_token_fee: u64,
+    master_active: bool,
+    fees_pull_mode: bool,
+    fees_collected: u64,
+    withdrawable_fees: u64,
+    total_launch_fees: u64,
+    total_refunds: u64,
+    issue_costs_spent: u64,
+}
+
+#[test]
+fn master_fuzz_test() {
+    for seed in 1..=FUZZ_SEEDS {
+        run_fuzz(seed);
+    }
+}
+
+fn run_fuzz(seed: u64) {
+    let mut setup: FuzzSetup = MasterSetup::new(
+        master::contract_obj,
+        bonding_mock::contract_obj,
+        router_mock::contract_obj,
+        oracle_mock::contract_obj,
+    );
+    let mut rng = FuzzRng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1);
+
+    let mut creators = vec![setup.user.clone()];
+    while creators.len() < FUZZ_CREATORS {
+        creators.push(setup.b_mock.create_user_account(&rust_biguint!(USER_BALANCE)));
+    }
+    let mut state = FuzzState {
+        creator_balances: vec![USER_BALANCE; creators.len()],
+        creators,
+        pending: Vec::new(),
+        launched_tokens: Vec::new(),
+        paused_bondings: Vec::new(),
+        new_token_fee: NEW_TOKEN_FEE,
+        master_active: true,
+        fees_pull_mode: false,
+        fees_collected: 0,
+        withdrawable_fees: 0,
+        total_launch_fees: 0,
+        total_refunds: 0,
+        issue_costs_spent: 0,
+    };
+
+    for _ in 0..FUZZ_STEPS {
+        match rng.below(11) {
+            0 | 1 => start_issuance(&mut setup, &mut rng, &mut state),
+            2 | 3 => resolve_issuance(&mut setup, &mut rng, &mut state, true),
+            4 => resolve_issuance(&mut setup, &mut rng, &mut state, false),
+            5 => change_config(&mut setup, &mut rng, &mut state),
+            6 => toggle_master(&mut setup, &mut state),
+            7 => upgrade_master(&mut setup, &mut rng, &mut state),
+            8 => toggle_fees_pull_mode(&mut setup, &mut state),
+            9 => withdraw_fees(&mut setup, &mut state),
+            _ => touch_bonding(&mut setup, &mut rng, &mut state),
+        }
+
+        check_invariants(&mut setup, &state);
+    }
+}
+
+fn start_issuance(
+    setup: &mut FuzzSetup,
+    rng: &mut FuzzRng,
+    state: &mut FuzzState,
+) {
+    let creator_index = rng.below(state.creators.len());
+    let creator = state.creators[creator_index].clone();
+    if state.creator_balances[creator_index] < state.new_token_fee {
+        return;
+    }
+
+    if !state.master_active {
+        setup
+            .call_new_token(&creator, state.new_token_fee)
+            .assert_user_error("Not active");
+        return;
+    }
+
+    setup
+        .call_new_token(&creator, state.new_token_fee - 1)
+        .assert_user_error("New Token Fee is not correct");
+
+    let issue = setup.start_issue(&creator, state.new_token_fee);
+    state.creator_balances[creator_index] -= state.new_token_fee;
+    state.issue_costs_spent += ISSUE_TOKEN_COST;
+    state.pending.push(PendingIssuance {
+        creator: creator_index,
+        launch_fee: state.new_token_fee,
+        issue,
+        legacy: false,
+    });
+}
+
+fn resolve_issuance(
+    setup: &mut FuzzSetup,
+    rng: &mut FuzzRng,
+    state: &mut FuzzState,
+    success: bool,
+) {
+    if state.pending.is_empty() {
+        return;
+    }
+
+    let index = rng.below(state.pending.len());
+    resolve_pending(setup, state, index, success);
+}
+
+fn resolve_pending(setup: &mut FuzzSetup, state: &mut FuzzState, index: usize, success: bool) {
+    // the callback does not depend on the master being active, nor on the current config
+    let issuance = state.pending.swap_remove(index);
+    if success {
+        let token_id = format!("TKN-{:06x}", state.launched_tokens.len() + 1).into_bytes();
+        let master_address = setup.master_wrapper.address_ref().clone();
+        let _ = setup
+            .b_mock
+            .prepare_deploy_from_sc(&master_address, setup.bonding_builder);
+        setup
+            .resolve_issue(&issuance.issue, &token_id, true)
+            .assert_ok();
+        state.launched_tokens.push(token_id);
+
+        let launch_fee = issuance.launch_fee - ISSUE_TOKEN_COST;
+        if state.fees_pull_mode {
+            state.withdrawable_fees += launch_fee;
+        } else {
+            state.fees_collected += launch_fee;
+        }
+        state.total_launch_fees += issuance.launch_fee;
+    } else {
+        setup
+            .resolve_issue(&issuance.issue, b"", false)
+            .assert_ok();
+        state.creator_balances[issuance.creator] += issuance.launch_fee;
+        state.issue_costs_spent -= ISSUE_TOKEN_COST;
+        state.total_refunds += issuance.launch_fee;
+    }
+}
+
+fn change_config(
+    setup: &mut FuzzSetup,
+    rng: &mut FuzzRng,
+    state: &mut FuzzState,
+) {
+    let owner = setup.owner.clone();
+    let new_token_fee = ISSUE_TOKEN_COST + 1 + rng.next() % NEW_TOKEN_FEE;
+    let initial_virtual_liquidity = 1 + rng.next() % INITIAL_VIRTUAL_LIQUIDITY;
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_new_token_fee(managed_biguint!(new_token_fee));
+            sc.set_initial_virtual_liquidity(managed_biguint!(initial_virtual_liquidity));
+        })
+        .assert_ok();
+    state.new_token_fee = new_token_fee;
+}
+
+fn toggle_master(setup: &mut FuzzSetup, state: &mut FuzzState) {
+    let owner = setup.owner.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+    let master_active = state.master_active;
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            if master_active {
+                sc.pause(managed_address!(&master_address));
+            } else {
+                sc.resume(managed_address!(&master_address));
+            }
+        })
+        .assert_ok();
+    state.master_active = !master_active;
+}
+
+/// An upgrade leaves the master paused and must keep the registry, the ledgers and the
+/// pending issuances as they were. The upgrade starts from a random older storage layout,
+/// which the migrations bring back in batches before the master can be resumed. Issuances,
+/// bondings and fees keep moving between the batches.
+fn upgrade_master(setup: &mut FuzzSetup, rng: &mut FuzzRng, state: &mut FuzzState) {
+    let owner = setup.owner.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+    let from_version = rng.below(CURRENT_STORAGE_VERSION as usize) as u32;
+
+    // launches still in flight when the upgrade lands, recorded the way the master being
+    // replaced did
+    let in_flight = state.pending.len();
+    for _ in 0..rng.below(3) {
+        start_issuance(setup, rng, state);
+    }
+    let legacy: Vec<_> = state
+        .pending
+        .split_off(in_flight)
+        .into_iter()
+        .map(|issuance| PendingIssuance {
+            issue: issuance.issue.into_legacy(),
+            legacy: true,
+            ..issuance
+        })
+        .collect();
+    let legacy_owed: u64 = legacy
+        .iter()
+        .map(|issuance| issuance.launch_fee - ISSUE_TOKEN_COST)
+        .sum();
+    state.pending.extend(legacy);
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.remove_owed(&EgldOrEsdtTokenIdentifier::egld(), &managed_biguint!(legacy_owed));
+            sc.storage_version().set(from_version);
+            if from_version < 1 {
+                sc.total_launches().clear();
+            }
+            if from_version < 2 {
+                sc.all_bondings().clear();
+            }
+            sc.upgrade();
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.resume(managed_address!(&master_address));
+        })
+        .assert_user_error("Storage migrations are pending");
+    state.master_active = false;
+
+    let mut pending_migrations = CURRENT_STORAGE_VERSION - from_version;
+    while pending_migrations > 0 {
+        for _ in 0..rng.below(3) {
+            migration_window_step(setup, rng, state);
+        }
+
+        let batch_size = 1 + rng.below(2);
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+                let left = sc.run_migrations(batch_size);
+                assert!(left <= pending_migrations);
+                pending_migrations = left;
+            })
+            .assert_ok();
+    }
+
+    // the callbacks of the legacy launches land within a few blocks, before any config
+    // change they would pick up
+    while let Some(index) = state.pending.iter().position(|issuance| issuance.legacy) {
+        let success = rng.below(2) == 0;
+        resolve_pending(setup, state, index, success);
+    }
+}
+
+/// The calls that do not wait for the master to be resumed.
+fn migration_window_step(setup: &mut FuzzSetup, rng: &mut FuzzRng, state: &mut FuzzState) {
+    match rng.below(5) {
+        0 => resolve_issuance(setup, rng, state, true),
+        1 => resolve_issuance(setup, rng, state, false),
+        2 => touch_bonding(setup, rng, state),
+        3 => toggle_fees_pull_mode(setup, state),
+        _ => withdraw_fees(setup, state),
+    }
+
+    // the counts are only right once the migrations are done, but they stay readable
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let _ = sc.get_platform_stats();
+        })
+        .assert_ok();
+}
+
+fn toggle_fees_pull_mode(setup: &mut FuzzSetup, state: &mut FuzzState) {
+    let owner = setup.owner.clone();
+    let fees_pull_mode = !state.fees_pull_mode;
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_fees_pull_mode(fees_pull_mode);
+        })
+        .assert_ok();
+    state.fees_pull_mode = fees_pull_mode;
+}
+
+fn withdraw_fees(setup: &mut FuzzSetup, state: &mut FuzzState) {
+    let fees_collector = setup.fees_collector.clone();
+    let result = setup
+        .b_mock
+        .execute_tx(&fees_collector, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let _ = sc.withdraw_fees(MultiValueEncoded::new());
+        });
+    if state.withdrawable_fees == 0 {
+        result.assert_user_error("No fees to withdraw");
+        return;
+    }
+
+    result.assert_ok();
+    state.fees_collected += state.withdrawable_fees;
+    state.withdrawable_fees = 0;
+}
+
+fn touch_bonding(
+    setup: &mut FuzzSetup,
+    rng: &mut FuzzRng,
+    state: &mut FuzzState,
+) {
+    if state.launched_tokens.is_empty() {
+        return;
+    }
+
+    let owner = setup.owner.clone();
+    let token_id = state.launched_tokens[rng.below(state.launched_tokens.len())].clone();
+    let bonding_address = setup.get_bonding_address(&token_id);
+
+    if rng.below(2) == 0 {
+        let is_paused = state.paused_bondings.contains(&bonding_address);
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+                if is_paused {
+                    sc.resume(managed_address!(&bonding_address));
+                } else {
+                    sc.pause(managed_address!(&bonding_address));
+                }
+            })
+            .assert_ok();
+
+        if is_paused {
+            state.paused_bondings.retain(|address| address != &bonding_address);
+        } else {
+            state.paused_bondings.push(bonding_address);
+        }
+        return;
+    }
+
+    let result = setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.upgrade_pair_endpoint(managed_token_id!(token_id.as_slice()), managed_token_id!(WEGLD_TOKEN_ID));
+        });
+    if state.master_active {
+        result.assert_ok();
+    } else {
+        result.assert_user_error("Not active");
+    }
+}
+
+fn check_invariants(setup: &mut FuzzSetup, state: &FuzzState) {
+    // pair_map and address_pair_map stay mirrored, one entry per successful launch
+    setup.check_registered_pairs(state.launched_tokens.len());
+
+    // creators only pay for the issuances that did not fail, the collector only gets
+    // what was forwarded or withdrawn
+    for (creator, expected_balance) in state.creators.iter().zip(state.creator_balances.iter()) {
+        setup
+            .b_mock
+            .check_egld_balance(creator, &rust_biguint!(*expected_balance));
+    }
+    setup
+        .b_mock
+        .check_egld_balance(&setup.fees_collector, &rust_biguint!(state.fees_collected));
+
+    // no EGLD is created or lost: what left the creators is in the collector, the master
+    // or paid to the system SC for issuances that went through
+    let creators_total = state
+        .creators
+        .iter()
+        .fold(rust_biguint!(0u64), |total, creator| total + setup.b_mock.get_egld_balance(creator));
+    assert_eq!(
+        creators_total
+            + setup.b_mock.get_egld_balance(&setup.fees_collector)
+            + setup.b_mock.get_egld_balance(setup.master_wrapper.address_ref())
+            + rust_biguint!(state.issue_costs_spent),
+        rust_biguint!(USER_BALANCE) * FUZZ_CREATORS as u64
+    );
+
+    // every successful launch produced exactly one bonding holding the issued supply
+    let mut bonding_addresses: Vec<Address> = Vec::new();
+    for token_id in state.launched_tokens.iter() {
+        let bonding_address = setup.get_bonding_address(token_id);
+        assert!(bonding_address != Address::zero());
+        assert!(!bonding_addresses.contains(&bonding_address));
+        setup
+            .b_mock
+            .check_esdt_balance(&bonding_address, token_id, &rust_biguint!(TOKEN_SUPPLY));
+        bonding_addresses.push(bonding_address);
+    }
+
+    let launched = state.launched_tokens.len() as u64;
+    let paused = state.paused_bondings.len() as u64;
+    // the master holds the fees of the pending issuances minus what went to the system SC,
+    // plus the fees waiting for withdrawal, and nothing else
+    let owed: u64 = state
+        .pending
+        .iter()
+        .map(|issuance| issuance.launch_fee - ISSUE_TOKEN_COST)
+        .sum();
+    setup.b_mock.check_egld_balance(
+        setup.master_wrapper.address_ref(),
+        &rust_biguint!(owed + state.withdrawable_fees),
+    );
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.total_launches, launched);
+            assert_eq!(stats.paused_bondings, paused);
+            assert_eq!(stats.active_bondings, launched - paused);
+            assert_eq!(stats.total_launch_fees, managed_biguint!(state.total_launch_fees));
+            assert_eq!(stats.total_refunds, managed_biguint!(state.total_refunds));
+            assert_eq!(stats.total_fees_forwarded, managed_biguint!(state.fees_collected));
+            assert_eq!(sc.is_active(), state.master_active);
+            assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+            assert_eq!(sc.all_bondings().len() as u64, launched);
+
+            let egld = EgldOrEsdtTokenIdentifier::egld();
+            assert_eq!(sc.owed_balance(&egld).get(), managed_biguint!(owed));
+            assert_eq!(sc.withdrawable_fees(&egld).get(), managed_biguint!(state.withdrawable_fees));
+            assert_eq!(sc.get_stray_balance(egld), managed_biguint!(0u64));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,374 @@
+//! Runs interactor commands against the master in the scenario debugger. The interactor
+//! crate stays out of the workspace, as its snippets dependency needs a network, so the
+//! modules that turn commands into steps are compiled into this test instead.
+
+#[path = "../interact/src/master_interact_calls.rs"]
+mod master_interact_calls;
+#[path = "../interact/src/master_interact_cli.rs"]
+mod master_interact_cli;
+#[path = "../interact/src/master_interact_output.rs"]
+mod master_interact_output;
+
+use clap::{CommandFactory, Parser};
+use master_interact_calls::{deploy_step, master_step, upgrade_step, MasterContract, MasterStep};
+use master_interact_cli::{InteractCli, InteractCliCommand};
+use multiversx_sc_scenario::{bech32, scenario_model::*, ScenarioWorld};
+
+const MASTER_CODE_PATH: &str = "mxsc:output/master.mxsc.json";
+const MASTER_ADDRESS: &str = "sc:master";
+const OWNER: &str = "address:owner";
+const USER: &str = "address:user";
+
+const USER_BALANCE: u64 = 1_000_000_000_000_000_000;
+
+struct InteractSetup {
+    world: ScenarioWorld,
+    master: MasterContract,
+}
+
+impl InteractSetup {
+    fn new() -> Self {
+        let mut world = ScenarioWorld::new();
+        world.register_contract(MASTER_CODE_PATH, master::ContractBuilder);
+        world.set_state_step(
+            SetStateStep::new()
+                .put_account(OWNER, Account::new().nonce(1))
+                .put_account(USER, Account::new().nonce(1).balance(USER_BALANCE))
+                .new_address(OWNER, 1, MASTER_ADDRESS),
+        );
+
+        let mut setup = InteractSetup {
+            world,
+            master: MasterContract::new(MASTER_ADDRESS),
+        };
+        let deploy = format!(
+            "deploy {}",
+            deploy_args(&bech32_of("address:fees-collector"))
+        );
+        setup.run(OWNER, &deploy);
+        setup
+    }
+
+    /// Runs a command line as the interactor would, and returns what it prints.
+    fn run(&mut self, from: &str, command_line: &str) -> Vec<String> {
+        self.run_expect(from, command_line, TxExpect::ok())
+    }
+
+    fn run_expect(&mut self, from: &str, command_line: &str, expect: TxExpect) -> Vec<String> {
+        let cli = InteractCli::try_parse_from(
+            std::iter::once("master-interact").chain(command_line.split_whitespace()),
+        )
+        .unwrap_or_else(|err| panic!("could not parse `{command_line}`: {err}"));
+
+        match cli.command.expect("no command") {
+            InteractCliCommand::Deploy(args) => {
+                let code = self.world.code_expression(MASTER_CODE_PATH);
+                let mut step = deploy_step(&mut self.master, &args)
+                    .from(from)
+                    .code(code)
+                    .expect(expect);
+                self.world.sc_deploy(&mut step);
+                Vec::new()
+            }
+            InteractCliCommand::Upgrade => {
+                let code = self.world.code_expression(MASTER_CODE_PATH);
+                let mut step = upgrade_step(&self.master, &code).from(from).expect(expect);
+                self.world.sc_call(&mut step);
+                Vec::new()
+            }
+            InteractCliCommand::Master(command) => match master_step(&mut self.master, &command) {
+                MasterStep::Call(step, printer) => {
+                    let mut step = step.from(from).expect(expect);
+                    self.world.sc_call(&mut step);
+                    printer(&step.response().out)
+                }
+                MasterStep::Query(mut step, printer) => {
+                    self.world.sc_query(&mut step);
+                    printer(&step.response().out)
+                }
+            },
+        }
+    }
+
+    fn run_error(&mut self, from: &str, command_line: &str, message: &str) {
+        self.run_expect(
+            from,
+            command_line,
+            TxExpect::user_error(format!("str:{message}")),
+        );
+    }
+}
+
+fn bech32_of(address_expr: &str) -> String {
+    bech32::encode(&AddressValue::from(address_expr).value)
+}
+
+/// Config arguments shared by `deploy` and `config set`.
+fn deploy_args(fees_collector: &str) -> String {
+    format!(
+        "--template {} --token-supply 1000000000 --fees-collector {fees_collector} \
+         --new-token-fee 100000000000000000 --ivl 1000000000000000000 --buy-token WEGLD-abcdef \
+         --oracle {} --max-market-cap 3000000000000000000 --router {} \
+         --issue-token-cost 50000000000000000 --wegld-unwrap-sc {} \
+         --reach-jeetdex-fee 10000000000000000",
+        bech32_of("sc:template"),
+        bech32_of("sc:oracle"),
+        bech32_of("sc:router"),
+        bech32_of("sc:wegld-unwrap"),
+    )
+}
+
+#[test]
+fn interact_cli_definition_test() {
+    InteractCli::command().debug_assert();
+}
+
+#[test]
+fn interact_config_test() {
+    let mut setup = InteractSetup::new();
+
+    assert_eq!(setup.run(OWNER, "config state"), ["true"]);
+    assert_eq!(setup.run(OWNER, "config token-supply"), ["1000000000"]);
+
+    let config = setup.run(OWNER, "config get");
+    assert!(config.contains(&format!(
+        "fees_collector: {}",
+        bech32_of("address:fees-collector")
+    )));
+    assert!(config.contains(&String::from("allowed_token: WEGLD-abcdef")));
+
+    let new_fees_collector = bech32_of("address:new-fees-collector");
+    let set_config = format!(
+        "config set {} --new-token-gas 1 --create-bonding-gas 3 \
+         --register-router-gas 4 --set-token-identifier-gas 5 --callback-gas 6",
+        deploy_args(&new_fees_collector),
+    );
+    setup.run(OWNER, &set_config);
+    assert!(setup
+        .run(OWNER, "config get")
+        .contains(&format!("fees_collector: {new_fees_collector}")));
+
+    assert_eq!(
+        setup.run(OWNER, "gas get"),
+        [
+            "new_token: 1",
+            "create_bonding: 3",
+            "register_router: 4",
+            "set_token_identifier: 5",
+            "callback: 6",
+        ]
+    );
+    assert_eq!(setup.run(OWNER, "gas new-token-limit"), ["60000019"]);
+
+    setup.run(
+        OWNER,
+        "gas set --new-token-gas 10 --create-bonding-gas 30 \
+         --register-router-gas 40 --set-token-identifier-gas 50 --callback-gas 60",
+    );
+    assert_eq!(setup.run(OWNER, "gas new-token-limit"), ["60000190"]);
+
+    setup.run_error(
+        USER,
+        "config set-token-supply 5",
+        "Endpoint can only be called by owner",
+    );
+    setup.run(OWNER, "config set-token-supply 5");
+    assert_eq!(setup.run(USER, "config token-supply"), ["5"]);
+
+    assert_eq!(setup.run(USER, "stats")[0], "total_launches: 0");
+    assert_eq!(setup.run(USER, "bondings list"), Vec::<String>::new());
+}
+
+#[test]
+fn interact_rate_limits_and_access_lists_test() {
+    let mut setup = InteractSetup::new();
+    let user = bech32_of(USER);
+
+    setup.run(OWNER, "rate-limits set 100 2 1");
+    assert_eq!(setup.run(USER, "rate-limits window-rounds"), ["100"]);
+    assert_eq!(setup.run(USER, "rate-limits max-per-window"), ["2"]);
+    assert_eq!(setup.run(USER, "rate-limits max-per-block"), ["1"]);
+
+    setup.run(OWNER, &format!("rate-limits add-exempt {user}"));
+    assert_eq!(setup.run(USER, "rate-limits exempt"), [user.clone()]);
+    assert_eq!(
+        setup.run(USER, &format!("rate-limits quota {user}"))[0],
+        "is_exempt: true"
+    );
+    setup.run(OWNER, &format!("rate-limits remove-exempt {user}"));
+    assert_eq!(setup.run(USER, "rate-limits exempt"), Vec::<String>::new());
+
+    setup.run(OWNER, "allowlist set-enabled true");
+    assert_eq!(setup.run(USER, "allowlist enabled"), ["true"]);
+    assert_eq!(
+        setup.run(USER, &format!("allowlist check {user}")),
+        ["false"]
+    );
+    setup.run(OWNER, &format!("allowlist add {user}"));
+    assert_eq!(
+        setup.run(USER, &format!("allowlist check {user}")),
+        ["true"]
+    );
+    assert_eq!(setup.run(USER, "allowlist get"), [user.clone()]);
+
+    setup.run(OWNER, &format!("blocklist add {user}"));
+    assert_eq!(
+        setup.run(USER, &format!("blocklist check {user}")),
+        ["true"]
+    );
+    setup.run_error(
+        USER,
+        "new-token Jeet JEET --fee 100000000000000000",
+        "Address is blocked from launching tokens",
+    );
+}
+
+#[test]
+fn interact_profiles_test() {
+    let mut setup = InteractSetup::new();
+
+    setup.run_error(
+        OWNER,
+        "anti-snipe set 7 10 5000 500",
+        "Launch profile does not exist",
+    );
+    setup.run(OWNER, "profiles add 7");
+    assert_eq!(setup.run(USER, "profiles list"), ["7"]);
+
+    setup.run(OWNER, "anti-snipe set 7 10 5000 500");
+    assert_eq!(
+        setup.run(USER, "anti-snipe get 7"),
+        [
+            "window_blocks: 10",
+            "max_buy_per_address: 5000",
+            "fee_percent: 500"
+        ]
+    );
+
+    setup.run(
+        OWNER,
+        "curve set-profile 7 --kind linear --initial-price 1000 --price-increment 10",
+    );
+    assert_eq!(
+        setup.run(USER, "curve profile 7"),
+        [
+            "kind: linear",
+            "initial_price: 1000",
+            "price_increment: 10",
+            "growth_percent: 0",
+            "step_size: 0",
+        ]
+    );
+
+    setup.run(OWNER, "vesting set-profile 7 100 1000");
+    assert_eq!(
+        setup.run(USER, "vesting profile 7"),
+        ["cliff_duration: 100", "vesting_duration: 1000"]
+    );
+
+    setup.run(OWNER, "graduation set-policy 7 lock 30");
+    assert_eq!(
+        setup.run(USER, "graduation policy 7"),
+        ["lp_policy: lock", "lock_epochs: 30"]
+    );
+}
+
+#[test]
+fn interact_treasury_and_param_push_test() {
+    let mut setup = InteractSetup::new();
+
+    setup.run(OWNER, "treasury set-pull-mode true");
+    assert_eq!(setup.run(USER, "treasury pull-mode"), ["true"]);
+    assert_eq!(setup.run(USER, "treasury stray-balance EGLD"), ["0"]);
+    assert_eq!(
+        setup.run(USER, "treasury owed-tokens"),
+        Vec::<String>::new()
+    );
+    assert_eq!(setup.run(USER, "treasury fee-tokens"), Vec::<String>::new());
+
+    assert_eq!(setup.run(USER, "bonding-params push"), ["none"]);
+    setup.run(
+        OWNER,
+        "bonding-params start-push max-market-cap 5000000000000000000 --filter launch-profile --profile 0",
+    );
+    assert_eq!(
+        setup.run(USER, "bonding-params push"),
+        [
+            "param: max-market-cap 5000000000000000000",
+            "filter: launch-profile 0",
+            "next_index: 1"
+        ]
+    );
+    assert_eq!(setup.run(OWNER, "bonding-params continue-push 10"), ["0"]);
+    assert_eq!(setup.run(USER, "bonding-params push"), ["none"]);
+}
+
+#[test]
+fn interact_upgrade_test() {
+    let mut setup = InteractSetup::new();
+    let master = bech32_of(MASTER_ADDRESS);
+
+    setup.run(OWNER, "upgrade");
+    assert_eq!(setup.run(USER, "config state"), ["false"]);
+
+    // nothing to migrate on a master deployed at the current layout
+    assert_eq!(setup.run(OWNER, "migrations run 10"), ["0"]);
+    setup.run(OWNER, &format!("resume {master}"));
+    assert_eq!(setup.run(USER, "config state"), ["true"]);
+}
+
+/// The owner endpoints added on top of the original config ones.
+const NEW_OWNER_ENDPOINTS: &[&str] = &[
+    "setGasSchedule",
+    "setConfig",
+    "runMigrations",
+    "removeLaunchProfile",
+    "addLaunchProfile",
+    "setProfileAntiSnipe",
+    "clearProfileAntiSnipe",
+    "setProfileCurve",
+    "clearProfileCurve",
+    "setProfileSupplyAllocation",
+    "clearProfileSupplyAllocation",
+    "setProfileGraduationPolicy",
+    "clearProfileGraduationPolicy",
+    "setProfileCreatorBuyVesting",
+    "clearProfileCreatorBuyVesting",
+    "setDexPairForBonding",
+    "setBondingParam",
+    "setBondingParamForBondings",
+    "startBondingParamPush",
+    "continueBondingParamPush",
+    "cancelBondingParamPush",
+    "setAllowlistEnabled",
+    "addToAllowlist",
+    "removeFromAllowlist",
+    "addToBlocklist",
+    "removeFromBlocklist",
+    "setLaunchRateLimits",
+    "addRateLimitExempt",
+    "removeRateLimitExempt",
+    "setCreatorFeeShare",
+    "moderateTokenMetadata",
+    "setRankingSize",
+    "setMaxStartDelay",
+    "setCancelRefundPercent",
+    "setFeesPullMode",
+    "recoverStrayFunds",
+];
+
+#[test]
+fn interact_owner_endpoints_test() {
+    let mut setup = InteractSetup::new();
+
+    // the caller is checked before the arguments are decoded
+    for endpoint in NEW_OWNER_ENDPOINTS {
+        setup.world.sc_call(
+            ScCallStep::new()
+                .from(USER)
+                .to(MASTER_ADDRESS)
+                .function(endpoint)
+                .expect(TxExpect::user_error("str:Endpoint can only be called by owner")),
+        );
+    }
+}
@@ -0,0 +1,215 @@
+multiversx_sc::imports!();
+
+use master::bonding_proxy::State;
+use master::factory::{LaunchSettings, PairData};
+
+/// Stand-in for the bonding contract. Endpoint names and signatures mirror the ones the
+/// master calls through `bonding_proxy` and `bonding_launch_proxy`.
+#[multiversx_sc::contract]
+pub trait BondingMock {
+    #[init]
+    fn init(
+        &self,
+        allowed_token: TokenIdentifier,
+        fees_collector: ManagedAddress,
+        initial_virtual_liquidity: BigUint,
+        oracle_address: ManagedAddress,
+        max_market_cap: BigUint,
+        jeetdex_router_sc_address: ManagedAddress,
+        issue_token_cost: BigUint,
+        wegld_unwrap_sc: ManagedAddress,
+        reach_jeetdex_fee: BigUint,
+        db_id: ManagedBuffer,
+        launch_settings: LaunchSettings<Self::Api>,
+    ) {
+        self.allowed_token().set(allowed_token);
+        self.fees_collector().set(fees_collector);
+        self.initial_virtual_liquidity().set(initial_virtual_liquidity);
+        self.oracle_address().set(oracle_address);
+        self.max_market_cap().set(max_market_cap);
+        self.jeetdex_router_sc_address().set(jeetdex_router_sc_address);
+        self.issue_token_cost().set(issue_token_cost);
+        self.wegld_unwrap_sc().set(wegld_unwrap_sc);
+        self.reach_jeetdex_fee().set(reach_jeetdex_fee);
+        if !db_id.is_empty() {
+            self.db_id().set(db_id);
+        }
+        self.launch_settings().set(launch_settings);
+        self.active().set(true);
+        self.init_calls().update(|calls| *calls += 1);
+    }
+
+    #[upgrade]
+    fn upgrade(
+        &self,
+        allowed_token: TokenIdentifier,
+        fees_collector: ManagedAddress,
+        initial_virtual_liquidity: BigUint,
+        oracle_address: ManagedAddress,
+        max_market_cap: BigUint,
+        jeetdex_router_sc_address: ManagedAddress,
+        issue_token_cost: BigUint,
+        wegld_unwrap_sc: ManagedAddress,
+        reach_jeetdex_fee: BigUint,
+        db_id: ManagedBuffer,
+        launch_settings: LaunchSettings<Self::Api>,
+    ) {
+        self.init(
+            allowed_token,
+            fees_collector,
+            initial_virtual_liquidity,
+            oracle_address,
+            max_market_cap,
+            jeetdex_router_sc_address,
+            issue_token_cost,
+            wegld_unwrap_sc,
+            reach_jeetdex_fee,
+            db_id,
+            launch_settings,
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint(setTokenIdentifier)]
+    fn set_token_identifier(
+        &self,
+        token_creator_buy: bool,
+        creator: ManagedAddress,
+        opt_buy_recipient: OptionalValue<ManagedAddress>,
+    ) {
+        let (token_id, mut amount) = self.call_value().single_fungible_esdt();
+        // the creator buy takes 1% of the curve
+        if token_creator_buy {
+            let bought = &amount / 100u32;
+            let buy_recipient = opt_buy_recipient.into_option().unwrap_or_else(|| creator.clone());
+            self.send().direct_esdt(&buy_recipient, &token_id, 0, &bought);
+            amount -= bought;
+        }
+        self.token_id().set(token_id);
+        self.token_reserve().set(amount);
+        self.token_creator_buy().set(token_creator_buy);
+        self.creator().set(creator);
+    }
+
+    #[endpoint]
+    fn pause(&self) {
+        self.active().set(false);
+    }
+
+    #[endpoint]
+    fn resume(&self) {
+        self.active().set(true);
+    }
+
+    #[endpoint(withdrawTokenReserve)]
+    fn withdraw_token_reserve(&self) -> BigUint {
+        require!(!self.active().get(), "Bonding is active");
+        let amount = self.token_reserve().take();
+        if amount > 0 {
+            let caller = self.blockchain().get_caller();
+            self.send().direct_esdt(&caller, &self.token_id().get(), 0, &amount);
+        }
+        amount
+    }
+
+    #[endpoint(setJeetDexRouter)]
+    fn set_jeetdex_router(&self, address: ManagedAddress) {
+        self.jeetdex_router_sc_address().set(address);
+    }
+
+    #[endpoint(setFeesCollector)]
+    fn set_fees_collector(&self, fees_collector: ManagedAddress) {
+        self.fees_collector().set(fees_collector);
+    }
+
+    #[endpoint(setOracleAddress)]
+    fn set_oracle_address(&self, oracle_address: ManagedAddress) {
+        self.oracle_address().set(oracle_address);
+    }
+
+    #[endpoint(setMaxMarketCap)]
+    fn set_max_market_cap(&self, max_market_cap: BigUint) {
+        self.max_market_cap().set(max_market_cap);
+    }
+
+    #[endpoint(setReachJeetdexFee)]
+    fn set_reach_jeetdex_fee(&self, reach_jeetdex_fee: BigUint) {
+        self.reach_jeetdex_fee().set(reach_jeetdex_fee);
+    }
+
+    #[endpoint(setWegldUnwrapSc)]
+    fn set_wegld_unwrap_sc(&self, wegld_unwrap_sc: ManagedAddress) {
+        self.wegld_unwrap_sc().set(wegld_unwrap_sc);
+    }
+
+    #[view(getPairData)]
+    fn get_pair_data(&self) -> PairData<Self::Api> {
+        PairData {
+            first_token_id: self.token_id().get(),
+            second_token_id: self.allowed_token().get(),
+            first_token_reserve: self.token_reserve().get(),
+            second_token_reserve: self.initial_virtual_liquidity().get(),
+            owner_fee_percent: 0,
+            market_cap: BigUint::zero(),
+            db_id: self.db_id().get(),
+            state: if self.active().get() {
+                State::Active
+            } else {
+                State::Inactive
+            },
+        }
+    }
+
+    #[storage_mapper("allowed_token")]
+    fn allowed_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("fees_collector")]
+    fn fees_collector(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("initial_virtual_liquidity")]
+    fn initial_virtual_liquidity(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("oracle_address")]
+    fn oracle_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("max_market_cap")]
+    fn max_market_cap(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("jeetdex_router_sc_address")]
+    fn jeetdex_router_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("issue_token_cost")]
+    fn issue_token_cost(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("wegld_unwrap_sc")]
+    fn wegld_unwrap_sc(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("reach_jeetdex_fee")]
+    fn reach_jeetdex_fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("db_id")]
+    fn db_id(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(isActive)]
+    #[storage_mapper("active")]
+    fn active(&self) -> SingleValueMapper<bool>;
+
+    #[view(getInitCalls)]
+    #[storage_mapper("init_calls")]
+    fn init_calls(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("token_id")]
+    fn token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("token_reserve")]
+    fn token_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("token_creator_buy")]
+    fn token_creator_buy(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("creator")]
+    fn creator(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("launch_settings")]
+    fn launch_settings(&self) -> SingleValueMapper<LaunchSettings<Self::Api>>;
+}
@@ -0,0 +1,386 @@
+#![allow(dead_code)]
+
+pub mod bonding_mock;
+pub mod oracle_mock;
+pub mod router_mock;
+
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::codec::TopDecode;
+use multiversx_sc::storage::{storage_get, StorageKey};
+use multiversx_sc::types::ManagedType;
+use multiversx_sc::types::{
+    Address, ManagedAddress, ManagedAsyncCallError, ManagedAsyncCallResult, ManagedBuffer, ManagedVec,
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, num_bigint, rust_biguint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    DebugApi,
+};
+
+use master::config::{ConfigModule, GasSchedule};
+use master::factory::FactoryModule;
+use master::ranking::RankingModule;
+use master::{LaunchRequest, MasterContract};
+use oracle_mock::OracleMock;
+use router_mock::RouterMock;
+
+pub const MASTER_WASM_PATH: &str = "output/master.wasm";
+pub const BONDING_MOCK_WASM_PATH: &str = "bonding-mock.wasm";
+pub const ROUTER_MOCK_WASM_PATH: &str = "router-mock.wasm";
+pub const ORACLE_MOCK_WASM_PATH: &str = "oracle-mock.wasm";
+
+pub const WEGLD_TOKEN_ID: &[u8] = b"WEGLD-abcdef";
+pub const FIRST_TOKEN_ID: &[u8] = b"JEET-123456";
+pub const SECOND_TOKEN_ID: &[u8] = b"DEGEN-123456";
+pub const THIRD_TOKEN_ID: &[u8] = b"MOON-123456";
+
+pub const TOKEN_SUPPLY: u64 = 1_000_000_000;
+pub const NEW_TOKEN_FEE: u64 = 100_000_000_000_000_000;
+pub const ISSUE_TOKEN_COST: u64 = 50_000_000_000_000_000;
+pub const INITIAL_VIRTUAL_LIQUIDITY: u64 = 1_000_000_000_000_000_000;
+pub const MAX_MARKET_CAP: u64 = 3_000_000_000_000_000_000;
+pub const REACH_JEETDEX_FEE: u64 = 10_000_000_000_000_000;
+pub const USER_BALANCE: u64 = 1_000_000_000_000_000_000;
+
+pub fn whitebox_gas_schedule() -> GasSchedule {
+    GasSchedule {
+        new_token: 5_000_000,
+        create_bonding: 10_000_000,
+        register_router: 5_000_000,
+        set_token_identifier: 10_000_000,
+        callback: 10_000_000,
+    }
+}
+
+/// An issuance `newToken` started, with the callback arguments it recorded, in the order
+/// `token_issue_callback` takes them.
+pub struct PendingIssue {
+    pub closure_args: Vec<Vec<u8>>,
+}
+
+impl PendingIssue {
+    fn arg<T: TopDecode>(&self, index: usize) -> T {
+        T::top_decode(self.closure_args[index].as_slice()).unwrap()
+    }
+
+    /// The same issuance as a master from before the launch options recorded it, with
+    /// only the caller, db id and creator buy flag.
+    pub fn into_legacy(mut self) -> Self {
+        self.closure_args.truncate(3);
+        self
+    }
+
+    fn request(&self) -> Option<LaunchRequest<DebugApi>> {
+        (self.closure_args.len() > 3).then(|| self.arg(3))
+    }
+
+    pub fn creator(&self) -> Address {
+        self.arg::<ManagedAddress<DebugApi>>(0).to_address()
+    }
+
+    fn call_callback(
+        &self,
+        sc: &master::ContractObj<DebugApi>,
+        result: ManagedAsyncCallResult<DebugApi, ()>,
+    ) {
+        sc.token_issue_callback(
+            &self.arg(0),
+            &self.arg(1),
+            &self.arg(2),
+            self.request().into(),
+            result,
+        );
+    }
+}
+
+/// Optional `newToken` arguments. A `start_timestamp` of 0 leaves it out, so that trading
+/// opens right away.
+#[derive(Clone, Copy, Default)]
+pub struct LaunchOptions {
+    pub start_timestamp: u64,
+    pub token_creator_buy: bool,
+    pub launch_profile: Option<u32>,
+}
+
+pub struct MasterSetup<MasterObjBuilder, BondingObjBuilder, RouterObjBuilder, OracleObjBuilder>
+where
+    MasterObjBuilder: 'static + Copy + Fn() -> master::ContractObj<DebugApi>,
+    BondingObjBuilder: 'static + Copy + Fn() -> bonding_mock::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router_mock::ContractObj<DebugApi>,
+    OracleObjBuilder: 'static + Copy + Fn() -> oracle_mock::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner: Address,
+    pub user: Address,
+    pub fees_collector: Address,
+    pub esdt_system: Address,
+    pub master_wrapper: ContractObjWrapper<master::ContractObj<DebugApi>, MasterObjBuilder>,
+    pub bonding_template_wrapper: ContractObjWrapper<bonding_mock::ContractObj<DebugApi>, BondingObjBuilder>,
+    pub router_wrapper: ContractObjWrapper<router_mock::ContractObj<DebugApi>, RouterObjBuilder>,
+    pub oracle_wrapper: ContractObjWrapper<oracle_mock::ContractObj<DebugApi>, OracleObjBuilder>,
+    pub bonding_builder: BondingObjBuilder,
+}
+
+impl<MasterObjBuilder, BondingObjBuilder, RouterObjBuilder, OracleObjBuilder>
+    MasterSetup<MasterObjBuilder, BondingObjBuilder, RouterObjBuilder, OracleObjBuilder>
+where
+    MasterObjBuilder: 'static + Copy + Fn() -> master::ContractObj<DebugApi>,
+    BondingObjBuilder: 'static + Copy + Fn() -> bonding_mock::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router_mock::ContractObj<DebugApi>,
+    OracleObjBuilder: 'static + Copy + Fn() -> oracle_mock::ContractObj<DebugApi>,
+{
+    pub fn new(
+        master_builder: MasterObjBuilder,
+        bonding_builder: BondingObjBuilder,
+        router_builder: RouterObjBuilder,
+        oracle_builder: OracleObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner = b_mock.create_user_account(&rust_zero);
+        let user = b_mock.create_user_account(&rust_biguint!(USER_BALANCE));
+        let fees_collector = b_mock.create_user_account(&rust_zero);
+        let esdt_system = b_mock.create_user_account(&rust_zero);
+
+        let bonding_template_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner),
+            bonding_builder,
+            BONDING_MOCK_WASM_PATH,
+        );
+        let router_wrapper =
+            b_mock.create_sc_account(&rust_zero, Some(&owner), router_builder, ROUTER_MOCK_WASM_PATH);
+        let oracle_wrapper =
+            b_mock.create_sc_account(&rust_zero, Some(&owner), oracle_builder, ORACLE_MOCK_WASM_PATH);
+        let master_wrapper =
+            b_mock.create_sc_account(&rust_zero, Some(&owner), master_builder, MASTER_WASM_PATH);
+
+        b_mock
+            .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+                sc.init();
+            })
+            .assert_ok();
+        b_mock
+            .execute_tx(&owner, &oracle_wrapper, &rust_zero, |sc| {
+                sc.init();
+            })
+            .assert_ok();
+
+        let mut setup = MasterSetup {
+            b_mock,
+            owner,
+            user,
+            fees_collector,
+            esdt_system,
+            master_wrapper,
+            bonding_template_wrapper,
+            router_wrapper,
+            oracle_wrapper,
+            bonding_builder,
+        };
+        setup.init_master().assert_ok();
+        setup.set_whitebox_gas_schedule();
+        setup
+    }
+
+    /// Whitebox transactions carry 100M gas, less than the default launch schedule needs.
+    pub fn set_whitebox_gas_schedule(&mut self) {
+        self.b_mock
+            .execute_tx(&self.owner, &self.master_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.set_gas_schedule(whitebox_gas_schedule());
+            })
+            .assert_ok();
+    }
+
+    pub fn init_master(&mut self) -> TxResult {
+        let template_address = self.bonding_template_wrapper.address_ref().clone();
+        let fees_collector = self.fees_collector.clone();
+        let oracle_address = self.oracle_wrapper.address_ref().clone();
+        let router_address = self.router_wrapper.address_ref().clone();
+
+        self.b_mock
+            .execute_tx(&self.owner, &self.master_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.init(
+                    managed_address!(&template_address),
+                    managed_biguint!(TOKEN_SUPPLY),
+                    managed_address!(&fees_collector),
+                    managed_biguint!(NEW_TOKEN_FEE),
+                    managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_address!(&oracle_address),
+                    managed_biguint!(MAX_MARKET_CAP),
+                    managed_address!(&router_address),
+                    managed_biguint!(ISSUE_TOKEN_COST),
+                    managed_address!(&oracle_address),
+                    managed_biguint!(REACH_JEETDEX_FEE),
+                );
+            })
+    }
+
+    pub fn call_new_token(&mut self, caller: &Address, fee: u64) -> TxResult {
+        self.call_new_token_with(caller, fee, LaunchOptions::default())
+    }
+
+    pub fn call_new_token_with(&mut self, caller: &Address, fee: u64, options: LaunchOptions) -> TxResult {
+        self.b_mock
+            .execute_tx(caller, &self.master_wrapper, &rust_biguint!(fee), |sc| {
+                sc.create_new_token_endpoint(
+                    managed_buffer!(b"JeetToken"),
+                    managed_buffer!(b"JEET"),
+                    managed_buffer!(b"db-id"),
+                    options.token_creator_buy,
+                    OptionalValue::None,
+                    if options.start_timestamp == 0 {
+                        OptionalValue::None
+                    } else {
+                        OptionalValue::Some(options.start_timestamp)
+                    },
+                    options.launch_profile.into(),
+                );
+            })
+    }
+
+    /// Runs `newToken` and returns the issue callback arguments it recorded. The test VM
+    /// does not answer the issue like the real system SC: the first issue goes to an
+    /// account that does not exist yet, the next ones get the token id back instead of the
+    /// supply, which the callback rejects. Either way the callback arguments stay stored,
+    /// and `resolve_issue` plays the callback the way the real system SC does.
+    pub fn start_issue(&mut self, caller: &Address, fee: u64) -> PendingIssue {
+        self.start_issue_with(caller, fee, LaunchOptions::default())
+    }
+
+    pub fn start_issue_with(&mut self, caller: &Address, fee: u64, options: LaunchOptions) -> PendingIssue {
+        self.call_new_token_with(caller, fee, options).assert_ok();
+
+        let mut closure_args = Vec::new();
+        self.b_mock
+            .execute_query(&self.master_wrapper, |_sc| {
+                // whitebox transactions all run with a zero tx hash
+                let mut storage_key = StorageKey::<DebugApi>::new(b"CB_CLOSURE");
+                storage_key.append_bytes(&[0u8; 32]);
+                let (callback_name, args): (ManagedBuffer<DebugApi>, ManagedVec<DebugApi, ManagedBuffer<DebugApi>>) =
+                    storage_get(storage_key.as_ref());
+                assert_eq!(callback_name, managed_buffer!(b"token_issue_callback"));
+                for arg in args.iter() {
+                    closure_args.push(arg.to_boxed_bytes().into_vec());
+                }
+            })
+            .assert_ok();
+        PendingIssue { closure_args }
+    }
+
+    /// Plays the issue callback of a pending issuance with the arguments `newToken`
+    /// recorded. On success the system SC sends the issued supply, on failure it gives the
+    /// issue cost back.
+    pub fn resolve_issue(&mut self, pending: &PendingIssue, token_id: &[u8], success: bool) -> TxResult {
+        if success {
+            self.b_mock
+                .set_esdt_balance(&self.esdt_system, token_id, &rust_biguint!(TOKEN_SUPPLY));
+            self.b_mock.execute_esdt_transfer(
+                &self.esdt_system,
+                &self.master_wrapper,
+                token_id,
+                0,
+                &rust_biguint!(TOKEN_SUPPLY),
+                |sc| pending.call_callback(&sc, ManagedAsyncCallResult::Ok(())),
+            )
+        } else {
+            // the test VM keeps the issue cost, the real system SC gives it back, the one
+            // of the config for a legacy issuance
+            let mut issue_cost = num_bigint::BigUint::default();
+            self.b_mock
+                .execute_query(&self.master_wrapper, |sc| {
+                    let cost = pending
+                        .request()
+                        .map_or_else(|| sc.issue_token_cost().get(), |request| request.issue_cost);
+                    issue_cost = num_bigint::BigUint::from_bytes_be(cost.to_bytes_be().as_slice());
+                })
+                .assert_ok();
+            self.b_mock.set_egld_balance(&self.esdt_system, &issue_cost);
+            self.b_mock.execute_tx(&self.esdt_system, &self.master_wrapper, &issue_cost, |sc| {
+                pending.call_callback(
+                    &sc,
+                    ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                        err_code: 1u32,
+                        err_msg: managed_buffer!(b"issue failed"),
+                    }),
+                )
+            })
+        }
+    }
+
+    /// Completes a launch and returns a wrapper over the bonding deployed by the master.
+    pub fn launch_token(
+        &mut self,
+        creator: &Address,
+        token_id: &[u8],
+    ) -> ContractObjWrapper<bonding_mock::ContractObj<DebugApi>, BondingObjBuilder> {
+        self.launch_token_with(creator, token_id, LaunchOptions::default())
+    }
+
+    pub fn launch_token_with(
+        &mut self,
+        creator: &Address,
+        token_id: &[u8],
+        options: LaunchOptions,
+    ) -> ContractObjWrapper<bonding_mock::ContractObj<DebugApi>, BondingObjBuilder> {
+        let new_token_fee = self.get_new_token_fee();
+        let pending = self.start_issue_with(creator, new_token_fee, options);
+
+        let master_address = self.master_wrapper.address_ref().clone();
+        let bonding_wrapper = self
+            .b_mock
+            .prepare_deploy_from_sc(&master_address, self.bonding_builder);
+        self.resolve_issue(&pending, token_id, true).assert_ok();
+        bonding_wrapper
+    }
+
+    pub fn fail_issue(&mut self, creator: &Address) -> TxResult {
+        let new_token_fee = self.get_new_token_fee();
+        let pending = self.start_issue(creator, new_token_fee);
+        self.resolve_issue(&pending, b"", false)
+    }
+
+    pub fn get_new_token_fee(&mut self) -> u64 {
+        let mut new_token_fee = 0u64;
+        self.b_mock
+            .execute_query(&self.master_wrapper, |sc| {
+                new_token_fee = sc.new_token_fee().get().to_u64().unwrap();
+            })
+            .assert_ok();
+        new_token_fee
+    }
+
+    pub fn get_bonding_address(&mut self, token_id: &[u8]) -> Address {
+        let mut bonding_address = Address::zero();
+        self.b_mock
+            .execute_query(&self.master_wrapper, |sc| {
+                bonding_address = sc
+                    .get_pair(managed_token_id!(token_id), managed_token_id!(WEGLD_TOKEN_ID))
+                    .to_address();
+            })
+            .assert_ok();
+        bonding_address
+    }
+
+    /// Reports a swap to the master the way a bonding does after every trade.
+    pub fn report_trade(&mut self, bonding_address: &Address, volume: u64, market_cap: u64) -> TxResult {
+        self.b_mock
+            .execute_tx(bonding_address, &self.master_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.report_trade(managed_biguint!(volume), managed_biguint!(market_cap));
+            })
+    }
+
+    pub fn check_registered_pairs(&mut self, expected_len: usize) {
+        self.b_mock
+            .execute_query(&self.master_wrapper, |sc| {
+                assert_eq!(sc.pair_map().len(), expected_len);
+                assert_eq!(sc.address_pair_map().len(), expected_len);
+                for (pair_tokens, bonding_address) in sc.pair_map().iter() {
+                    let mirrored_tokens = sc.address_pair_map().get(&bonding_address).unwrap();
+                    assert!(mirrored_tokens == pair_tokens);
+                }
+            })
+            .assert_ok();
+    }
+}
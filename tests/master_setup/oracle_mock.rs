@@ -0,0 +1,13 @@
+multiversx_sc::imports!();
+
+/// Stand-in for the xExchange oracle, quotes every swap one to one.
+#[multiversx_sc::contract]
+pub trait OracleMock {
+    #[init]
+    fn init(&self) {}
+
+    #[view(getAmountOut)]
+    fn get_amount_out_view(&self, _token_in: TokenIdentifier, amount_in: BigUint) -> BigUint {
+        amount_in
+    }
+}
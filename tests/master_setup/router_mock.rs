@@ -0,0 +1,17 @@
+multiversx_sc::imports!();
+
+/// Stand-in for the JeetDex router, only records the bondings registered by the master.
+#[multiversx_sc::contract]
+pub trait RouterMock {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint(setTempDegenPair)]
+    fn set_temp_degen_pair(&self, address: ManagedAddress) {
+        self.temp_degen_pairs().insert(address);
+    }
+
+    #[view(getTempDegenPairs)]
+    #[storage_mapper("temp_degen_pairs")]
+    fn temp_degen_pairs(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
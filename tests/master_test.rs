@@ -0,0 +1,3341 @@
+mod master_setup;
+
+use master_setup::bonding_mock::BondingMock;
+use master_setup::router_mock::RouterMock;
+use master_setup::*;
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::storage::mappers::StorageClearable;
+use multiversx_sc::types::{Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+
+use master::airdrop::{AirdropModule, Hash};
+use master::allocation::{AllocationModule, SupplyAllocation};
+use master::anti_snipe::AntiSnipeModule;
+use master::bonding_overrides::{BondingFilter, BondingOverridesModule, BondingParam};
+use master::config::{ConfigModule, GasSchedule, DEAD_ADDRESS, MAX_GAS_PER_TX};
+use master::creator::CreatorModule;
+use master::creator_fees::CreatorFeesModule;
+use master::curve::{CurveKind, CurveModule, CurveParams};
+use master::factory::FactoryModule;
+use master::graduation::{GraduationModule, LpPolicy};
+use master::metadata::{MetadataModule, TokenMetadata};
+use master::migration::{MigrationModule, CURRENT_STORAGE_VERSION};
+use master::profile::{LaunchProfileModule, DEFAULT_LAUNCH_PROFILE};
+use master::rate_limit::RateLimitModule;
+use master::ranking::{RankingKind, RankingModule};
+use master::schedule::ScheduleModule;
+use master::stats::StatsModule;
+use master::treasury::{FeeSource, TreasuryModule};
+use master::vesting::{VestingModule, VestingSchedule};
+use master::MasterContract;
+
+fn setup() -> MasterSetup<
+    impl Fn() -> master::ContractObj<DebugApi> + Copy,
+    impl Fn() -> bonding_mock::ContractObj<DebugApi> + Copy,
+    impl Fn() -> router_mock::ContractObj<DebugApi> + Copy,
+    impl Fn() -> oracle_mock::ContractObj<DebugApi> + Copy,
+> {
+    MasterSetup::new(
+        master::contract_obj,
+        bonding_mock::contract_obj,
+        router_mock::contract_obj,
+        oracle_mock::contract_obj,
+    )
+}
+
+#[test]
+fn init_test() {
+    let mut setup = setup();
+    let template_address = setup.bonding_template_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert!(sc.is_active());
+            assert_eq!(sc.pair_template_address().get(), managed_address!(&template_address));
+            assert_eq!(sc.token_supply().get(), managed_biguint!(TOKEN_SUPPLY));
+            assert_eq!(sc.new_token_fee().get(), managed_biguint!(NEW_TOKEN_FEE));
+            assert_eq!(sc.issue_token_cost().get(), managed_biguint!(ISSUE_TOKEN_COST));
+            assert_eq!(sc.allowed_token().get(), managed_token_id!(WEGLD_TOKEN_ID));
+            assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn init_zero_template_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let other_master_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0u64),
+        Some(&owner),
+        master::contract_obj,
+        MASTER_WASM_PATH,
+    );
+    let fees_collector = setup.fees_collector.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &other_master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.init(
+                managed_address!(&Address::zero()),
+                managed_biguint!(TOKEN_SUPPLY),
+                managed_address!(&fees_collector),
+                managed_biguint!(NEW_TOKEN_FEE),
+                managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY),
+                managed_token_id!(WEGLD_TOKEN_ID),
+                managed_address!(&fees_collector),
+                managed_biguint!(MAX_MARKET_CAP),
+                managed_address!(&fees_collector),
+                managed_biguint!(ISSUE_TOKEN_COST),
+                managed_address!(&fees_collector),
+                managed_biguint!(REACH_JEETDEX_FEE),
+            );
+        })
+        .assert_user_error("template cannot be zero");
+}
+
+#[test]
+fn new_token_wrong_fee_test() {
+    let mut setup = setup();
+    let user = setup.user.clone();
+
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE - 1)
+        .assert_user_error("New Token Fee is not correct");
+}
+
+#[test]
+fn new_token_paused_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.pause(managed_address!(&master_address));
+        })
+        .assert_ok();
+
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Not active");
+}
+
+#[test]
+fn new_token_blocklisted_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut addresses = multiversx_sc::types::MultiValueEncoded::new();
+            addresses.push(managed_address!(&user));
+            sc.add_to_blocklist(addresses);
+        })
+        .assert_ok();
+
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Address is blocked from launching tokens");
+}
+
+#[test]
+fn new_token_allowlist_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let user_list = || {
+        let mut addresses = MultiValueEncoded::new();
+        addresses.push(managed_address!(&user));
+        addresses
+    };
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_allowlist_enabled(true);
+        })
+        .assert_ok();
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Address is not allowed to launch tokens");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.add_to_allowlist(user_list());
+            assert!(sc.is_allowlisted(managed_address!(&user)));
+        })
+        .assert_ok();
+    setup.call_new_token(&user, NEW_TOKEN_FEE).assert_ok();
+
+    // the blocklist wins over the allowlist
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.add_to_blocklist(user_list());
+            assert!(sc.is_blocklisted(managed_address!(&user)));
+        })
+        .assert_ok();
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Address is blocked from launching tokens");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.remove_from_blocklist(user_list());
+            sc.remove_from_allowlist(user_list());
+            assert!(!sc.is_blocklisted(managed_address!(&user)));
+            assert!(!sc.is_allowlisted(managed_address!(&user)));
+        })
+        .assert_ok();
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Address is not allowed to launch tokens");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_allowlist_enabled(false);
+        })
+        .assert_ok();
+    setup.call_new_token(&user, NEW_TOKEN_FEE).assert_ok();
+}
+
+#[test]
+fn rate_limit_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let other_user = setup.b_mock.create_user_account(&rust_biguint!(USER_BALANCE));
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_launch_rate_limits(0, 5, 0);
+        })
+        .assert_user_error("Launch window cannot be zero");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_launch_rate_limits(10, 2, 3);
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_nonce(1);
+    setup.b_mock.set_block_round(1);
+    setup.call_new_token(&user, NEW_TOKEN_FEE).assert_ok();
+    setup.call_new_token(&user, NEW_TOKEN_FEE).assert_ok();
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Launch limit for this address reached, try again later");
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let quota = sc.get_remaining_launch_quota(managed_address!(&user));
+            assert!(!quota.is_exempt);
+            assert_eq!(quota.remaining_in_window, 0);
+            assert_eq!(quota.window_end_round, 11);
+            assert_eq!(quota.remaining_in_block, 1);
+        })
+        .assert_ok();
+
+    setup.call_new_token(&other_user, NEW_TOKEN_FEE).assert_ok();
+    setup
+        .call_new_token(&other_user, NEW_TOKEN_FEE)
+        .assert_user_error("Launch limit for this block reached, try again in the next block");
+
+    // the block limit resets every block, the address limit once the window is over
+    setup.b_mock.set_block_nonce(2);
+    setup.b_mock.set_block_round(2);
+    setup.call_new_token(&other_user, NEW_TOKEN_FEE).assert_ok();
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Launch limit for this address reached, try again later");
+
+    setup.b_mock.set_block_nonce(3);
+    setup.b_mock.set_block_round(11);
+    setup.call_new_token(&user, NEW_TOKEN_FEE).assert_ok();
+
+    // exempt addresses skip both limits
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&user));
+            sc.add_rate_limit_exempt(addresses);
+        })
+        .assert_ok();
+    for _ in 0..3 {
+        setup.call_new_token(&user, NEW_TOKEN_FEE).assert_ok();
+    }
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert!(sc.get_remaining_launch_quota(managed_address!(&user)).is_exempt);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&user));
+            sc.remove_rate_limit_exempt(addresses);
+        })
+        .assert_ok();
+    // exempt launches are not counted against the window
+    setup.call_new_token(&user, NEW_TOKEN_FEE).assert_ok();
+    setup
+        .call_new_token(&user, NEW_TOKEN_FEE)
+        .assert_user_error("Launch limit for this address reached, try again later");
+}
+
+#[test]
+fn issue_success_test() {
+    let mut setup = setup();
+    let user = setup.user.clone();
+
+    let bonding_wrapper = setup.launch_token(&user, FIRST_TOKEN_ID);
+    let bonding_address = bonding_wrapper.address_ref().clone();
+
+    setup.check_registered_pairs(1);
+    assert_eq!(setup.get_bonding_address(FIRST_TOKEN_ID), bonding_address);
+
+    setup
+        .b_mock
+        .check_esdt_balance(&bonding_address, FIRST_TOKEN_ID, &rust_biguint!(TOKEN_SUPPLY));
+    setup
+        .b_mock
+        .check_egld_balance(&user, &rust_biguint!(USER_BALANCE - NEW_TOKEN_FEE));
+    setup.b_mock.check_egld_balance(
+        &setup.fees_collector,
+        &rust_biguint!(NEW_TOKEN_FEE - ISSUE_TOKEN_COST),
+    );
+    setup
+        .b_mock
+        .check_egld_balance(setup.master_wrapper.address_ref(), &rust_biguint!(0u64));
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert_eq!(sc.bonding_creator(&managed_address!(&bonding_address)).get(), managed_address!(&user));
+
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.total_launches, 1);
+            assert_eq!(stats.active_bondings, 1);
+            assert_eq!(stats.total_launch_fees, managed_biguint!(NEW_TOKEN_FEE));
+            assert_eq!(
+                stats.total_fees_forwarded,
+                managed_biguint!(NEW_TOKEN_FEE - ISSUE_TOKEN_COST)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.router_wrapper, |sc| {
+            assert!(sc.temp_degen_pairs().contains(&managed_address!(&bonding_address)));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| {
+            assert_eq!(sc.token_id().get(), managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(sc.creator().get(), managed_address!(&user));
+            assert_eq!(sc.init_calls().get(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn issue_failure_refund_test() {
+    let mut setup = setup();
+    let user = setup.user.clone();
+
+    setup.fail_issue(&user).assert_ok();
+
+    setup.check_registered_pairs(0);
+    setup
+        .b_mock
+        .check_egld_balance(&user, &rust_biguint!(USER_BALANCE));
+    setup
+        .b_mock
+        .check_egld_balance(setup.master_wrapper.address_ref(), &rust_biguint!(0u64));
+    setup
+        .b_mock
+        .check_egld_balance(&setup.fees_collector, &rust_biguint!(0u64));
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.total_launches, 0);
+            assert_eq!(stats.total_refunds, managed_biguint!(NEW_TOKEN_FEE));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn upgrade_token_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let bonding_wrapper = setup.launch_token(&user, FIRST_TOKEN_ID);
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.upgrade_pair_endpoint(
+                managed_token_id!(FIRST_TOKEN_ID),
+                managed_token_id!(WEGLD_TOKEN_ID),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| {
+            assert_eq!(sc.init_calls().get(), 2);
+            assert_eq!(sc.token_id().get(), managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.upgrade_pair_endpoint(
+                managed_token_id!(SECOND_TOKEN_ID),
+                managed_token_id!(WEGLD_TOKEN_ID),
+            );
+        })
+        .assert_user_error("Bonding does not exists");
+
+}
+
+#[test]
+fn pause_resume_master_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.pause(managed_address!(&master_address));
+            assert!(!sc.is_active());
+
+            sc.resume(managed_address!(&master_address));
+            assert!(sc.is_active());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn pause_resume_bonding_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let bonding_wrapper = setup.launch_token(&user, FIRST_TOKEN_ID);
+    let bonding_address = bonding_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.pause(managed_address!(&bonding_address));
+            assert_eq!(sc.get_platform_stats().paused_bondings, 1);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| {
+            assert!(!sc.active().get());
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.resume(managed_address!(&bonding_address));
+            assert_eq!(sc.get_platform_stats().paused_bondings, 0);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| {
+            assert!(sc.active().get());
+        })
+        .assert_ok();
+
+    // a graduated bonding is only counted as graduated, even when paused
+    let dex_pair = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let graduated_address = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.pause(managed_address!(&bonding_address));
+            sc.set_dex_pair_for_bonding(managed_address!(&graduated_address), managed_address!(&dex_pair));
+            sc.pause(managed_address!(&graduated_address));
+
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.total_launches, 2);
+            assert_eq!(stats.paused_bondings, 1);
+            assert_eq!(stats.graduated_bondings, 1);
+            assert_eq!(stats.active_bondings, 0);
+
+            sc.resume(managed_address!(&bonding_address));
+            sc.resume(managed_address!(&graduated_address));
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.paused_bondings, 0);
+            assert_eq!(stats.graduated_bondings, 1);
+            assert_eq!(stats.active_bondings, 1);
+        })
+        .assert_ok();
+
+    let stranger = setup.fees_collector.clone();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.pause(managed_address!(&stranger));
+        })
+        .assert_user_error("Not a pair SC");
+}
+
+#[test]
+fn set_jeetdex_router_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let new_router = setup
+        .b_mock
+        .create_sc_account(
+            &rust_biguint!(0u64),
+            Some(&owner),
+            router_mock::contract_obj,
+            ROUTER_MOCK_WASM_PATH,
+        )
+        .address_ref()
+        .clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+    let bonding_wrapper = setup.launch_token(&user, FIRST_TOKEN_ID);
+    let bonding_address = bonding_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_jeetdex_router(managed_address!(&master_address), managed_address!(&new_router));
+            assert_eq!(sc.jeetdex_router_sc_address().get(), managed_address!(&new_router));
+
+            sc.set_jeetdex_router(managed_address!(&bonding_address), managed_address!(&new_router));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| {
+            assert_eq!(sc.jeetdex_router_sc_address().get(), managed_address!(&new_router));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn config_setters_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let new_address = setup.user.clone();
+    let new_template_address = setup
+        .b_mock
+        .create_sc_account(
+            &rust_biguint!(0u64),
+            Some(&owner),
+            bonding_mock::contract_obj,
+            BONDING_MOCK_WASM_PATH,
+        )
+        .address_ref()
+        .clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_fees_collector(managed_address!(&new_address));
+            assert_eq!(sc.fees_collector().get(), managed_address!(&new_address));
+
+            sc.set_initial_virtual_liquidity(managed_biguint!(2 * INITIAL_VIRTUAL_LIQUIDITY));
+            assert_eq!(
+                sc.initial_virtual_liquidity().get(),
+                managed_biguint!(2 * INITIAL_VIRTUAL_LIQUIDITY)
+            );
+
+            sc.set_token_supply(managed_biguint!(2 * TOKEN_SUPPLY));
+            assert_eq!(sc.token_supply().get(), managed_biguint!(2 * TOKEN_SUPPLY));
+
+            sc.set_new_token_fee(managed_biguint!(2 * NEW_TOKEN_FEE));
+            assert_eq!(sc.new_token_fee().get(), managed_biguint!(2 * NEW_TOKEN_FEE));
+
+            sc.set_max_market_cap(managed_biguint!(2 * MAX_MARKET_CAP));
+            assert_eq!(sc.max_market_cap().get(), managed_biguint!(2 * MAX_MARKET_CAP));
+
+            sc.set_bonding_template_address(managed_address!(&new_template_address));
+            assert_eq!(sc.pair_template_address().get(), managed_address!(&new_template_address));
+
+            sc.set_allowlist_enabled(true);
+            assert!(sc.allowlist_enabled().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn config_setters_validation_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_fees_collector(managed_address!(&Address::zero()));
+        })
+        .assert_user_error("Fees collector cannot be zero address");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_token_supply(managed_biguint!(0u64));
+        })
+        .assert_user_error("Token Supply cannot be zero");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_new_token_fee(managed_biguint!(0u64));
+        })
+        .assert_user_error("Token Fee cannot be zero");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_max_market_cap(managed_biguint!(0u64));
+        })
+        .assert_user_error("max_market_cap cannot be zero");
+}
+
+#[test]
+fn gas_schedule_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let fresh_master_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0u64),
+        Some(&owner),
+        master::contract_obj,
+        MASTER_WASM_PATH,
+    );
+
+    setup
+        .b_mock
+        .execute_query(&fresh_master_wrapper, |sc| {
+            let default_schedule = GasSchedule::default_schedule();
+            assert_eq!(sc.get_gas_schedule(), default_schedule);
+            // the gas `newToken` checked at the issue call before the schedule existed
+            assert_eq!(default_schedule.issue_and_callback_gas(), 150_000_000);
+            assert_eq!(
+                sc.get_new_token_gas_limit(),
+                default_schedule.new_token + default_schedule.issue_and_callback_gas()
+            );
+        })
+        .assert_ok();
+
+    let gas_schedule = GasSchedule {
+        new_token: 10_000_000,
+        create_bonding: 50_000_000,
+        register_router: 5_000_000,
+        set_token_identifier: 20_000_000,
+        callback: 15_000_000,
+    };
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_gas_schedule(gas_schedule.clone());
+            assert_eq!(sc.get_gas_schedule(), gas_schedule);
+            assert_eq!(sc.get_new_token_gas_limit(), 160_000_000);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_gas_schedule(GasSchedule {
+                callback: 0,
+                ..GasSchedule::default_schedule()
+            });
+        })
+        .assert_user_error("Gas schedule steps cannot be zero");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_gas_schedule(GasSchedule {
+                create_bonding: MAX_GAS_PER_TX,
+                ..GasSchedule::default_schedule()
+            });
+        })
+        .assert_user_error("Gas schedule exceeds the max gas per transaction");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_gas_schedule(GasSchedule {
+                callback: u64::MAX,
+                ..GasSchedule::default_schedule()
+            });
+        })
+        .assert_user_error("Gas schedule exceeds the max gas per transaction");
+
+    let oversized_schedule = GasSchedule {
+        create_bonding: u64::MAX,
+        ..GasSchedule::default_schedule()
+    };
+    assert_eq!(oversized_schedule.issue_and_callback_gas(), MAX_GAS_PER_TX);
+    assert_eq!(oversized_schedule.total_gas(), None);
+}
+
+#[test]
+fn get_set_config_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let new_address = setup.user.clone();
+    let template_address = setup.bonding_template_wrapper.address_ref().clone();
+    let fees_collector = setup.fees_collector.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut config = sc.get_config();
+            assert_eq!(config.pair_template_address, managed_address!(&template_address));
+            assert_eq!(config.fees_collector, managed_address!(&fees_collector));
+            assert_eq!(config.new_token_fee, managed_biguint!(NEW_TOKEN_FEE));
+            assert_eq!(config.issue_token_cost, managed_biguint!(ISSUE_TOKEN_COST));
+            assert_eq!(config.allowed_token, managed_token_id!(WEGLD_TOKEN_ID));
+            assert_eq!(config.gas_schedule, whitebox_gas_schedule());
+
+            config.fees_collector = managed_address!(&new_address);
+            config.max_market_cap = managed_biguint!(2 * MAX_MARKET_CAP);
+            config.reach_jeetdex_fee = managed_biguint!(2 * REACH_JEETDEX_FEE);
+            sc.set_config(config.clone());
+            assert_eq!(sc.get_config(), config);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut config = sc.get_config();
+            config.allowed_token = managed_token_id!(FIRST_TOKEN_ID);
+            sc.set_config(config);
+        })
+        .assert_user_error("Allowed token cannot be changed");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut config = sc.get_config();
+            config.reach_jeetdex_fee = managed_biguint!(0u64);
+            sc.set_config(config);
+        })
+        .assert_user_error("reach_jeetdex_fee cannot be zero");
+}
+
+#[test]
+fn config_invariants_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_new_token_fee(managed_biguint!(ISSUE_TOKEN_COST - 1));
+        })
+        .assert_user_error("Token Fee must cover issue_token_cost");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_initial_virtual_liquidity(managed_biguint!(MAX_MARKET_CAP));
+        })
+        .assert_user_error("max_market_cap must be above IVL");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_max_market_cap(managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY));
+        })
+        .assert_user_error("max_market_cap must be above IVL");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_bonding_template_address(managed_address!(&user));
+        })
+        .assert_user_error("template must be a smart contract");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_jeetdex_router(managed_address!(&master_address), managed_address!(&user));
+        })
+        .assert_user_error("jeetdex_router_sc_address must be a smart contract");
+
+    // both fields move together, which no sequence of single setters allows
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut config = sc.get_config();
+            config.initial_virtual_liquidity = managed_biguint!(2 * MAX_MARKET_CAP);
+            config.max_market_cap = managed_biguint!(3 * MAX_MARKET_CAP);
+            sc.set_config(config);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn init_oracle_validation_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let other_master_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0u64),
+        Some(&owner),
+        master::contract_obj,
+        MASTER_WASM_PATH,
+    );
+    let template_address = setup.bonding_template_wrapper.address_ref().clone();
+    let router_address = setup.router_wrapper.address_ref().clone();
+    let fees_collector = setup.fees_collector.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &other_master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.init(
+                managed_address!(&template_address),
+                managed_biguint!(TOKEN_SUPPLY),
+                managed_address!(&fees_collector),
+                managed_biguint!(NEW_TOKEN_FEE),
+                managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY),
+                managed_token_id!(WEGLD_TOKEN_ID),
+                managed_address!(&Address::zero()),
+                managed_biguint!(MAX_MARKET_CAP),
+                managed_address!(&router_address),
+                managed_biguint!(ISSUE_TOKEN_COST),
+                managed_address!(&router_address),
+                managed_biguint!(REACH_JEETDEX_FEE),
+            );
+        })
+        .assert_user_error("oracle cannot be zero");
+}
+
+#[test]
+fn bonding_param_push_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let fees_collector = setup.fees_collector.clone();
+    let new_fees_collector = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let first_bonding_wrapper = setup.launch_token(&user, FIRST_TOKEN_ID);
+    let second_bonding_wrapper = setup.launch_token(&user, SECOND_TOKEN_ID);
+    let first_bonding_address = first_bonding_wrapper.address_ref().clone();
+
+    // a single bonding, kept across an upgrade of that bonding
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_bonding_param(
+                managed_address!(&first_bonding_address),
+                BondingParam::MaxMarketCap(managed_biguint!(2 * MAX_MARKET_CAP)),
+            );
+            sc.upgrade_pair_endpoint(
+                managed_token_id!(FIRST_TOKEN_ID),
+                managed_token_id!(WEGLD_TOKEN_ID),
+            );
+
+            let params = sc.get_bonding_params(managed_address!(&first_bonding_address));
+            assert_eq!(params.max_market_cap, managed_biguint!(2 * MAX_MARKET_CAP));
+            assert_eq!(params.fees_collector, managed_address!(&fees_collector));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&first_bonding_wrapper, |sc| {
+            assert_eq!(sc.init_calls().get(), 2);
+            assert_eq!(sc.max_market_cap().get(), managed_biguint!(2 * MAX_MARKET_CAP));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&second_bonding_wrapper, |sc| {
+            assert_eq!(sc.max_market_cap().get(), managed_biguint!(MAX_MARKET_CAP));
+        })
+        .assert_ok();
+
+    // every bonding, one per batch
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.start_bonding_param_push(
+                BondingParam::FeesCollector(managed_address!(&new_fees_collector)),
+                BondingFilter::All,
+            );
+            assert_eq!(sc.continue_bonding_param_push(1), 1);
+            assert!(sc.get_bonding_param_push().into_option().is_some());
+            assert_eq!(sc.continue_bonding_param_push(1), 0);
+            assert!(sc.get_bonding_param_push().into_option().is_none());
+        })
+        .assert_ok();
+    for bonding_wrapper in [&first_bonding_wrapper, &second_bonding_wrapper] {
+        setup
+            .b_mock
+            .execute_query(bonding_wrapper, |sc| {
+                assert_eq!(sc.fees_collector().get(), managed_address!(&new_fees_collector));
+            })
+            .assert_ok();
+    }
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.continue_bonding_param_push(1);
+        })
+        .assert_user_error("No bonding param push in progress");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.start_bonding_param_push(
+                BondingParam::OracleAddress(managed_address!(&new_fees_collector)),
+                BondingFilter::NotGraduated,
+            );
+        })
+        .assert_user_error("oracle must be a smart contract");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_bonding_param(
+                managed_address!(&first_bonding_address),
+                BondingParam::MaxMarketCap(managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY)),
+            );
+        })
+        .assert_user_error("max_market_cap must be above IVL");
+}
+
+#[test]
+fn bonding_param_validated_against_bonding_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let bonding_address = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+
+    // the factory IVL is now above the max market cap pushed, the IVL of the bonding is not
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_initial_virtual_liquidity(managed_biguint!(MAX_MARKET_CAP - 1));
+            sc.set_bonding_param(
+                managed_address!(&bonding_address),
+                BondingParam::MaxMarketCap(managed_biguint!(2 * INITIAL_VIRTUAL_LIQUIDITY)),
+            );
+
+            let params = sc.get_bonding_params(managed_address!(&bonding_address));
+            assert_eq!(params.max_market_cap, managed_biguint!(2 * INITIAL_VIRTUAL_LIQUIDITY));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_bonding_param(
+                managed_address!(&bonding_address),
+                BondingParam::MaxMarketCap(managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY)),
+            );
+        })
+        .assert_user_error("max_market_cap must be above IVL");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_bonding_param(
+                managed_address!(&bonding_address),
+                BondingParam::FeesCollector(managed_address!(&Address::zero())),
+            );
+        })
+        .assert_user_error("Fees collector cannot be zero address");
+}
+
+#[test]
+fn bonding_params_kept_across_factory_changes_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let fees_collector = setup.fees_collector.clone();
+    let new_fees_collector = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let master_address = setup.master_wrapper.address_ref().clone();
+    let router_address = setup.router_wrapper.address_ref().clone();
+    let new_router_address = setup.oracle_wrapper.address_ref().clone();
+    let first_bonding_wrapper = setup.launch_token(&user, FIRST_TOKEN_ID);
+    let first_bonding_address = first_bonding_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_max_market_cap(managed_biguint!(2 * MAX_MARKET_CAP));
+            sc.set_fees_collector(managed_address!(&new_fees_collector));
+        })
+        .assert_ok();
+    let second_bonding_address = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let params = sc.get_bonding_params(managed_address!(&first_bonding_address));
+            assert_eq!(params.max_market_cap, managed_biguint!(MAX_MARKET_CAP));
+            assert_eq!(params.fees_collector, managed_address!(&fees_collector));
+
+            let params = sc.get_bonding_params(managed_address!(&second_bonding_address));
+            assert_eq!(params.max_market_cap, managed_biguint!(2 * MAX_MARKET_CAP));
+            assert_eq!(params.fees_collector, managed_address!(&new_fees_collector));
+        })
+        .assert_ok();
+
+    // progress is measured against the max market cap each bonding was deployed with
+    setup.report_trade(&first_bonding_address, 100, MAX_MARKET_CAP / 2).assert_ok();
+    setup.report_trade(&second_bonding_address, 100, MAX_MARKET_CAP / 2).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let by_progress: Vec<_> = sc.get_top_by_market_cap_progress().to_vec().iter().collect();
+            assert_eq!(by_progress.len(), 2);
+            assert_eq!(by_progress[0].sc_address, managed_address!(&first_bonding_address));
+            assert_eq!(by_progress[0].market_cap_progress, managed_biguint!(5_000u64));
+            assert_eq!(by_progress[1].market_cap_progress, managed_biguint!(2_500u64));
+        })
+        .assert_ok();
+
+    // an upgrade keeps what the bonding runs with, a router set on it is recorded
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_jeetdex_router(managed_address!(&master_address), managed_address!(&new_router_address));
+            sc.upgrade_pair_endpoint(managed_token_id!(FIRST_TOKEN_ID), managed_token_id!(WEGLD_TOKEN_ID));
+
+            let params = sc.get_bonding_params(managed_address!(&first_bonding_address));
+            assert_eq!(params.jeetdex_router_sc_address, managed_address!(&router_address));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_jeetdex_router(
+                managed_address!(&first_bonding_address),
+                managed_address!(&new_router_address),
+            );
+            let params = sc.get_bonding_params(managed_address!(&first_bonding_address));
+            assert_eq!(params.jeetdex_router_sc_address, managed_address!(&new_router_address));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&first_bonding_wrapper, |sc| {
+            assert_eq!(sc.init_calls().get(), 2);
+            assert_eq!(sc.max_market_cap().get(), managed_biguint!(MAX_MARKET_CAP));
+            assert_eq!(sc.fees_collector().get(), managed_address!(&fees_collector));
+            assert_eq!(sc.jeetdex_router_sc_address().get(), managed_address!(&new_router_address));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn bonding_param_subset_and_cancel_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let bonding_wrappers = [
+        setup.launch_token(&user, FIRST_TOKEN_ID),
+        setup.launch_token(&user, SECOND_TOKEN_ID),
+        setup.launch_token(&user, THIRD_TOKEN_ID),
+    ];
+    let bonding_addresses: Vec<Address> = bonding_wrappers
+        .iter()
+        .map(|bonding_wrapper| bonding_wrapper.address_ref().clone())
+        .collect();
+
+    // only the first and the third bonding are listed
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut listed = MultiValueEncoded::new();
+            listed.push(managed_address!(&bonding_addresses[0]));
+            listed.push(managed_address!(&bonding_addresses[2]));
+            sc.set_bonding_param_for_bondings(
+                BondingParam::ReachJeetdexFee(managed_biguint!(2 * REACH_JEETDEX_FEE)),
+                listed,
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut listed = MultiValueEncoded::new();
+            listed.push(managed_address!(&bonding_addresses[1]));
+            listed.push(managed_address!(&user));
+            sc.set_bonding_param_for_bondings(
+                BondingParam::ReachJeetdexFee(managed_biguint!(3 * REACH_JEETDEX_FEE)),
+                listed,
+            );
+        })
+        .assert_user_error("Not a pair SC");
+
+    // a push cancelled after the first batch leaves the other bondings as they were
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.start_bonding_param_push(
+                BondingParam::MaxMarketCap(managed_biguint!(2 * MAX_MARKET_CAP)),
+                BondingFilter::All,
+            );
+            assert_eq!(sc.continue_bonding_param_push(1), 2);
+            sc.cancel_bonding_param_push();
+            assert!(sc.get_bonding_param_push().into_option().is_none());
+        })
+        .assert_ok();
+
+    let expected = [
+        (2 * REACH_JEETDEX_FEE, 2 * MAX_MARKET_CAP),
+        (REACH_JEETDEX_FEE, MAX_MARKET_CAP),
+        (2 * REACH_JEETDEX_FEE, MAX_MARKET_CAP),
+    ];
+    for (bonding_wrapper, (reach_jeetdex_fee, max_market_cap)) in bonding_wrappers.iter().zip(expected) {
+        setup
+            .b_mock
+            .execute_query(bonding_wrapper, |sc| {
+                assert_eq!(sc.reach_jeetdex_fee().get(), managed_biguint!(reach_jeetdex_fee));
+                assert_eq!(sc.max_market_cap().get(), managed_biguint!(max_market_cap));
+            })
+            .assert_ok();
+    }
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            for (bonding_address, (reach_jeetdex_fee, max_market_cap)) in bonding_addresses.iter().zip(expected) {
+                let params = sc.get_bonding_params(managed_address!(bonding_address));
+                assert_eq!(params.reach_jeetdex_fee, managed_biguint!(reach_jeetdex_fee));
+                assert_eq!(params.max_market_cap, managed_biguint!(max_market_cap));
+            }
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.cancel_bonding_param_push();
+        })
+        .assert_user_error("No bonding param push in progress");
+}
+
+#[test]
+fn treasury_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let fees_collector = setup.fees_collector.clone();
+    let recipient = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let master_address = setup.master_wrapper.address_ref().clone();
+    let launch_fee_kept = NEW_TOKEN_FEE - ISSUE_TOKEN_COST;
+    let stray_amount = 5_000u64;
+
+    // in pull mode the launch fee stays in the master until withdrawn
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_fees_pull_mode(true);
+        })
+        .assert_ok();
+    setup.launch_token(&user, FIRST_TOKEN_ID);
+    setup
+        .b_mock
+        .check_egld_balance(&master_address, &rust_biguint!(launch_fee_kept));
+    setup
+        .b_mock
+        .check_egld_balance(&fees_collector, &rust_biguint!(0u64));
+
+    // funds nobody is owed can be recovered, fees waiting for withdrawal cannot
+    let master_balance = setup.b_mock.get_egld_balance(&master_address);
+    setup
+        .b_mock
+        .set_egld_balance(&master_address, &(master_balance + rust_biguint!(stray_amount)));
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let egld = EgldOrEsdtTokenIdentifier::egld();
+            assert_eq!(sc.withdrawable_fees(&egld).get(), managed_biguint!(launch_fee_kept));
+            assert_eq!(sc.owed_balance(&egld).get(), managed_biguint!(0u64));
+            assert_eq!(sc.get_stray_balance(egld), managed_biguint!(stray_amount));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let recovered = sc.recover_stray_funds(
+                EgldOrEsdtTokenIdentifier::egld(),
+                OptionalValue::Some(managed_address!(&recipient)),
+            );
+            assert_eq!(recovered, managed_biguint!(stray_amount));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&recipient, &rust_biguint!(stray_amount));
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.recover_stray_funds(EgldOrEsdtTokenIdentifier::egld(), OptionalValue::None);
+        })
+        .assert_user_error("No stray funds for this token");
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.withdraw_fees(MultiValueEncoded::new());
+        })
+        .assert_user_error("Only the owner or the fees collector can withdraw fees");
+    setup
+        .b_mock
+        .execute_tx(&fees_collector, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.withdraw_fees(MultiValueEncoded::new());
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_egld_balance(&fees_collector, &rust_biguint!(launch_fee_kept));
+    setup
+        .b_mock
+        .check_egld_balance(&master_address, &rust_biguint!(0u64));
+    setup
+        .b_mock
+        .execute_tx(&fees_collector, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.withdraw_fees(MultiValueEncoded::new());
+        })
+        .assert_user_error("No fees to withdraw");
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let egld = EgldOrEsdtTokenIdentifier::egld();
+            assert_eq!(
+                sc.fees_accrued(FeeSource::LaunchFee, &egld).get(),
+                managed_biguint!(launch_fee_kept)
+            );
+            assert_eq!(sc.fees_withdrawn(&egld).get(), managed_biguint!(launch_fee_kept));
+            assert_eq!(sc.withdrawable_fees(&egld).get(), managed_biguint!(0u64));
+            assert_eq!(
+                sc.get_platform_stats().total_fees_forwarded,
+                managed_biguint!(launch_fee_kept)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn supply_allocation_validation_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let treasury = setup.fees_collector.clone();
+
+    let allocation = |dex_reserve_percent: u64, treasury_percent: u64| SupplyAllocation::<DebugApi> {
+        dex_reserve_percent,
+        airdrop_percent: 0,
+        treasury_percent,
+        treasury_address: managed_address!(&treasury),
+        dev_percent: 0,
+        dev_cliff_duration: 0,
+        dev_vesting_duration: 0,
+    };
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_supply_allocation(DEFAULT_LAUNCH_PROFILE, allocation(1_500, 500));
+            let stored = sc.profile_supply_allocation(DEFAULT_LAUNCH_PROFILE).get();
+            assert_eq!(stored.dex_reserve_percent, 1_500);
+            assert_eq!(stored.treasury_percent, 500);
+            assert_eq!(
+                stored.curve_amount(&managed_biguint!(TOKEN_SUPPLY)),
+                managed_biguint!(TOKEN_SUPPLY * 8 / 10)
+            );
+        })
+        .assert_ok();
+    // the curve gets whatever the other allocations leave, which cannot be nothing
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_supply_allocation(DEFAULT_LAUNCH_PROFILE, allocation(8_000, 2_000));
+        })
+        .assert_user_error("Curve allocation cannot be zero");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_supply_allocation(DEFAULT_LAUNCH_PROFILE, allocation(8_000, 3_000));
+        })
+        .assert_user_error("Supply allocation cannot exceed 100%");
+    // wraps around to below 100% without the per-field bound
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_supply_allocation(DEFAULT_LAUNCH_PROFILE, allocation(u64::MAX, 1));
+        })
+        .assert_user_error("Supply allocation cannot exceed 100%");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_supply_allocation(7, allocation(0, 0));
+        })
+        .assert_user_error("Launch profile does not exist");
+    // the dev share is only ever sent through a vesting schedule
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut dev_allocation = allocation(0, 0);
+            dev_allocation.dev_percent = 1_000;
+            sc.set_profile_supply_allocation(DEFAULT_LAUNCH_PROFILE, dev_allocation);
+        })
+        .assert_user_error("Lock duration cannot be zero");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut dev_allocation = allocation(0, 0);
+            dev_allocation.dev_percent = 1_000;
+            dev_allocation.dev_cliff_duration = u64::MAX;
+            sc.set_profile_supply_allocation(DEFAULT_LAUNCH_PROFILE, dev_allocation);
+        })
+        .assert_user_error("Lock duration too long");
+}
+
+/// Known vector computed off-chain: leaves are `sha256(address ++ amount)` with the amount
+/// big-endian and unpadded, pairs are hashed sorted.
+/// root = H(H(leaf_a, leaf_b), leaf_c)
+const AIRDROP_LEAF_A: &str = "63eb0ec53c41946097171a1549f2cff2163bcec016fb2e4334b2272ef907821e";
+const AIRDROP_LEAF_B: &str = "84bf448f3768178fa5751b2c036431eeee2e9038bcf91fa8dfff1cbe4135cc0a";
+const AIRDROP_LEAF_C: &str = "a6ab043d42a982b1d435ade6a6c71b383474e66cbf9386f2faa6892e6f7c5449";
+const AIRDROP_NODE_AB: &str = "395470c8feca8491d075039dd8360fd546f009a08c3cf5a3f32ed11d54329970";
+const AIRDROP_ROOT: &str = "61d01f377bed21052fb7ff84b7b01747cb337abfa5a493571277252e13a18d1d";
+
+fn merkle_hash(hex_hash: &str) -> Hash<DebugApi> {
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(hex_hash, &mut bytes).unwrap();
+    Hash::new_from_bytes(&bytes)
+}
+
+fn merkle_proof(hex_hashes: &[&str]) -> MultiValueEncoded<DebugApi, Hash<DebugApi>> {
+    let mut proof = MultiValueEncoded::new();
+    for hex_hash in hex_hashes {
+        proof.push(merkle_hash(hex_hash));
+    }
+    proof
+}
+
+#[test]
+fn airdrop_merkle_test() {
+    let mut setup = setup();
+    let user = setup.user.clone();
+    let _ = setup.launch_token(&user, FIRST_TOKEN_ID);
+
+    let claimer_a = Address::from([0x11u8; 32]);
+    let claimer_b = Address::from([0x22u8; 32]);
+    let claimer_c = Address::from([0x33u8; 32]);
+    for claimer in [&claimer_a, &claimer_b, &claimer_c] {
+        setup
+            .b_mock
+            .create_account_raw(claimer, &rust_biguint!(0u64), None, None, None);
+    }
+
+    setup.b_mock.set_block_timestamp(100);
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(6_000u64), |sc| {
+            sc.create_airdrop(managed_token_id!(FIRST_TOKEN_ID), merkle_hash(AIRDROP_ROOT), 1_000);
+        })
+        .assert_user_error("Invalid airdrop token");
+    setup
+        .b_mock
+        .set_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(6_000u64));
+    setup
+        .b_mock
+        .execute_esdt_transfer(&user, &setup.master_wrapper, FIRST_TOKEN_ID, 0, &rust_biguint!(6_000u64), |sc| {
+            let airdrop_id =
+                sc.create_airdrop(managed_token_id!(FIRST_TOKEN_ID), merkle_hash(AIRDROP_ROOT), 1_000);
+            assert_eq!(airdrop_id, 1);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&claimer_a, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_airdrop(1, managed_biguint!(1_000u64), merkle_proof(&[AIRDROP_LEAF_B, AIRDROP_LEAF_C]));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&claimer_c, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_airdrop(1, managed_biguint!(3_000u64), merkle_proof(&[AIRDROP_NODE_AB]));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&claimer_a, FIRST_TOKEN_ID, &rust_biguint!(1_000u64));
+    setup
+        .b_mock
+        .check_esdt_balance(&claimer_c, FIRST_TOKEN_ID, &rust_biguint!(3_000u64));
+
+    setup
+        .b_mock
+        .execute_tx(&claimer_a, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_airdrop(1, managed_biguint!(1_000u64), merkle_proof(&[AIRDROP_LEAF_B, AIRDROP_LEAF_C]));
+        })
+        .assert_user_error("Airdrop already claimed");
+    // a proof only holds for its own address and amount
+    setup
+        .b_mock
+        .execute_tx(&claimer_b, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_airdrop(1, managed_biguint!(2_001u64), merkle_proof(&[AIRDROP_LEAF_A, AIRDROP_LEAF_C]));
+        })
+        .assert_user_error("Invalid Merkle proof");
+    setup
+        .b_mock
+        .execute_tx(&claimer_b, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_airdrop(1, managed_biguint!(1_000u64), merkle_proof(&[AIRDROP_LEAF_B, AIRDROP_LEAF_C]));
+        })
+        .assert_user_error("Invalid Merkle proof");
+    setup
+        .b_mock
+        .execute_tx(&claimer_b, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_airdrop(1, managed_biguint!(2_000u64), merkle_proof(&[AIRDROP_LEAF_A]));
+        })
+        .assert_user_error("Invalid Merkle proof");
+
+    // after expiry the unclaimed share goes back to the creator, once
+    setup.b_mock.set_block_timestamp(1_000);
+    setup
+        .b_mock
+        .execute_tx(&claimer_b, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_airdrop(1, managed_biguint!(2_000u64), merkle_proof(&[AIRDROP_LEAF_A, AIRDROP_LEAF_C]));
+        })
+        .assert_user_error("Airdrop has expired");
+    setup
+        .b_mock
+        .execute_tx(&claimer_b, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.reclaim_airdrop(1);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&claimer_b, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.reclaim_airdrop(1);
+        })
+        .assert_user_error("Airdrop already reclaimed");
+    setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(2_000u64));
+    setup
+        .b_mock
+        .check_esdt_balance(setup.master_wrapper.address_ref(), FIRST_TOKEN_ID, &rust_biguint!(0u64));
+}
+
+#[test]
+fn token_metadata_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let _ = setup.launch_token(&user, FIRST_TOKEN_ID);
+
+    let metadata = |description: &[u8]| {
+        let mut metadata = TokenMetadata::<DebugApi>::empty();
+        metadata.description = managed_buffer!(description);
+        metadata.website = managed_buffer!(b"https://jeet.example");
+        metadata
+    };
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_token_metadata(managed_token_id!(FIRST_TOKEN_ID), metadata(b"to the moon"));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_token_metadata(managed_token_id!(FIRST_TOKEN_ID), metadata(b"rug"));
+        })
+        .assert_user_error("Only the token creator can perform this action");
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_token_metadata(managed_token_id!(FIRST_TOKEN_ID), metadata(&[b'a'; 1_001]));
+        })
+        .assert_user_error("Description is too long");
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let stored = sc.token_metadata(&managed_token_id!(FIRST_TOKEN_ID)).get();
+            assert_eq!(stored.description, managed_buffer!(b"to the moon"));
+        })
+        .assert_ok();
+
+    // moderation can lock the entry against the creator
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.moderate_token_metadata(managed_token_id!(FIRST_TOKEN_ID), metadata(b"moderated"), true);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_token_metadata(managed_token_id!(FIRST_TOKEN_ID), metadata(b"to the moon"));
+        })
+        .assert_user_error("Token metadata is locked by moderation");
+
+    // clearing and unlocking hands the entry back to the creator
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.moderate_token_metadata(managed_token_id!(FIRST_TOKEN_ID), TokenMetadata::empty(), false);
+            assert!(sc.token_metadata(&managed_token_id!(FIRST_TOKEN_ID)).is_empty());
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_token_metadata(managed_token_id!(FIRST_TOKEN_ID), metadata(b"back"));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.moderate_token_metadata(managed_token_id!(SECOND_TOKEN_ID), TokenMetadata::empty(), false);
+        })
+        .assert_user_error("Token was not launched by this factory");
+}
+
+#[test]
+fn creator_transfer_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let new_creator = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let _ = setup.launch_token(&user, FIRST_TOKEN_ID);
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_creator_transfer(managed_token_id!(FIRST_TOKEN_ID), managed_address!(&Address::zero()));
+        })
+        .assert_user_error("New creator cannot be zero address");
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_creator_transfer(managed_token_id!(FIRST_TOKEN_ID), managed_address!(&user));
+        })
+        .assert_user_error("Address is already the creator");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_creator_transfer(managed_token_id!(FIRST_TOKEN_ID), managed_address!(&owner));
+        })
+        .assert_user_error("Only the token creator can perform this action");
+
+    // a cancelled proposal cannot be accepted
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_creator_transfer(managed_token_id!(FIRST_TOKEN_ID), managed_address!(&new_creator));
+            assert!(
+                sc.get_pending_creator(managed_token_id!(FIRST_TOKEN_ID)).into_option()
+                    == Some(managed_address!(&new_creator))
+            );
+            sc.cancel_creator_transfer(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&new_creator, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.accept_creator_transfer(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("No pending creator transfer");
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_creator_transfer(managed_token_id!(FIRST_TOKEN_ID), managed_address!(&new_creator));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.accept_creator_transfer(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Only the proposed creator can accept the transfer");
+    setup
+        .b_mock
+        .execute_tx(&new_creator, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.accept_creator_transfer(managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(
+                sc.get_token_creator(managed_token_id!(FIRST_TOKEN_ID)),
+                managed_address!(&new_creator)
+            );
+            assert!(sc.get_pending_creator(managed_token_id!(FIRST_TOKEN_ID)).into_option().is_none());
+        })
+        .assert_ok();
+
+    // the previous creator lost the creator rights
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_creator_transfer(managed_token_id!(FIRST_TOKEN_ID), managed_address!(&user));
+        })
+        .assert_user_error("Only the token creator can perform this action");
+}
+
+#[test]
+fn creator_fees_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let fees_collector = setup.fees_collector.clone();
+    let new_creator = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let bonding_address = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_creator_fee_share(10_001);
+        })
+        .assert_user_error("Creator fee share too high");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_creator_fee_share(2_000);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_esdt_balance(&bonding_address, WEGLD_TOKEN_ID, &rust_biguint!(1_500u64));
+    setup
+        .b_mock
+        .execute_esdt_transfer(&bonding_address, &setup.master_wrapper, WEGLD_TOKEN_ID, 0, &rust_biguint!(1_000u64), |sc| {
+            sc.deposit_swap_fees();
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .set_esdt_balance(&user, WEGLD_TOKEN_ID, &rust_biguint!(1_000u64));
+    setup
+        .b_mock
+        .execute_esdt_transfer(&user, &setup.master_wrapper, WEGLD_TOKEN_ID, 0, &rust_biguint!(1_000u64), |sc| {
+            sc.deposit_swap_fees();
+        })
+        .assert_user_error("Not a pair SC");
+
+    // the platform share leaves right away, the creator share waits for a claim
+    setup
+        .b_mock
+        .check_esdt_balance(&fees_collector, WEGLD_TOKEN_ID, &rust_biguint!(800u64));
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let payments: Vec<_> = sc.claim_creator_fees().into_iter().collect();
+            assert_eq!(payments.len(), 1);
+            assert_eq!(payments[0].amount, managed_biguint!(200u64));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&user, WEGLD_TOKEN_ID, &rust_biguint!(1_200u64));
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_creator_fees();
+        })
+        .assert_user_error("No fees to claim");
+
+    // fees deposited after a creator transfer go to the new creator
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_creator_transfer(managed_token_id!(FIRST_TOKEN_ID), managed_address!(&new_creator));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&new_creator, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.accept_creator_transfer(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_esdt_transfer(&bonding_address, &setup.master_wrapper, WEGLD_TOKEN_ID, 0, &rust_biguint!(500u64), |sc| {
+            sc.deposit_swap_fees();
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert!(sc.get_claimable_creator_fees(managed_address!(&user)).is_empty());
+            let claimable: Vec<_> = sc
+                .get_claimable_creator_fees(managed_address!(&new_creator))
+                .into_iter()
+                .collect();
+            assert_eq!(claimable[0].amount, managed_biguint!(100u64));
+
+            let history = sc.get_creator_fees_history(managed_address!(&user)).to_vec();
+            assert_eq!(history.get(0).total_earned, managed_biguint!(200u64));
+            assert_eq!(history.get(0).total_claimed, managed_biguint!(200u64));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn supply_allocation_split_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let treasury = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let tenth = TOKEN_SUPPLY / 10;
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_supply_allocation(
+                DEFAULT_LAUNCH_PROFILE,
+                SupplyAllocation {
+                    dex_reserve_percent: 1_000,
+                    airdrop_percent: 1_000,
+                    treasury_percent: 1_000,
+                    treasury_address: managed_address!(&treasury),
+                    dev_percent: 1_000,
+                    dev_cliff_duration: 100,
+                    dev_vesting_duration: 1_000,
+                },
+            );
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(50);
+    let bonding_wrapper = setup.launch_token(&user, FIRST_TOKEN_ID);
+    let bonding_address = bonding_wrapper.address_ref().clone();
+
+    // the curve goes to the bonding, the treasury share leaves, the rest stays owed
+    setup
+        .b_mock
+        .check_esdt_balance(&bonding_address, FIRST_TOKEN_ID, &rust_biguint!(6 * tenth));
+    setup
+        .b_mock
+        .check_esdt_balance(&treasury, FIRST_TOKEN_ID, &rust_biguint!(tenth));
+    setup
+        .b_mock
+        .check_esdt_balance(setup.master_wrapper.address_ref(), FIRST_TOKEN_ID, &rust_biguint!(3 * tenth));
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let token_id = managed_token_id!(FIRST_TOKEN_ID);
+            assert_eq!(sc.dex_liquidity_reserve(&token_id).get(), managed_biguint!(tenth));
+            assert_eq!(sc.airdrop_reserve(&token_id).get(), managed_biguint!(tenth));
+            assert_eq!(
+                sc.get_stray_balance(EgldOrEsdtTokenIdentifier::esdt(token_id.clone())),
+                managed_biguint!(0u64)
+            );
+
+            let schedules: Vec<_> = sc.get_vesting_schedules(token_id).into_iter().collect();
+            assert_eq!(schedules.len(), 1);
+            assert_eq!(schedules[0].total_amount, managed_biguint!(tenth));
+            assert_eq!(schedules[0].start_timestamp, 50);
+            assert_eq!(schedules[0].cliff_duration, 100);
+            assert_eq!(schedules[0].vesting_duration, 1_000);
+        })
+        .assert_ok();
+
+    // only the bonding can pull its DEX reserve, once
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_dex_reserve();
+        })
+        .assert_user_error("Not a pair SC");
+    setup
+        .b_mock
+        .execute_tx(&bonding_address, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            assert_eq!(sc.claim_dex_reserve(), managed_biguint!(tenth));
+            assert_eq!(sc.claim_dex_reserve(), managed_biguint!(0u64));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&bonding_address, FIRST_TOKEN_ID, &rust_biguint!(7 * tenth));
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert_eq!(
+                sc.get_stray_balance(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(FIRST_TOKEN_ID))),
+                managed_biguint!(0u64)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ranking_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let first_bonding = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+    let second_bonding = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+    let third_bonding = setup.launch_token(&user, THIRD_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_ranking_size(2);
+            sc.set_bonding_param(
+                managed_address!(&first_bonding),
+                BondingParam::MaxMarketCap(managed_biguint!(2 * MAX_MARKET_CAP)),
+            );
+        })
+        .assert_ok();
+
+    let hour = 3_600u64;
+    setup.b_mock.set_block_timestamp(10 * hour);
+    setup.report_trade(&first_bonding, 100, MAX_MARKET_CAP).assert_ok();
+    setup.report_trade(&second_bonding, 200, MAX_MARKET_CAP / 2).assert_ok();
+    // the third bonding pushes out the lowest volume, but not the highest progress
+    setup.report_trade(&third_bonding, 150, MAX_MARKET_CAP / 10).assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let by_volume: Vec<_> = sc.get_top_by_volume().to_vec().iter().collect();
+            assert_eq!(by_volume.len(), 2);
+            assert_eq!(by_volume[0].sc_address, managed_address!(&second_bonding));
+            assert_eq!(by_volume[1].sc_address, managed_address!(&third_bonding));
+
+            // the first bonding runs with twice the max market cap
+            let by_progress: Vec<_> = sc.get_top_by_market_cap_progress().to_vec().iter().collect();
+            assert_eq!(by_progress.len(), 2);
+            assert_eq!(by_progress[0].sc_address, managed_address!(&first_bonding));
+            assert_eq!(by_progress[0].market_cap_progress, managed_biguint!(5_000u64));
+            assert_eq!(by_progress[1].sc_address, managed_address!(&second_bonding));
+            assert_eq!(by_progress[1].market_cap_progress, managed_biguint!(5_000u64));
+        })
+        .assert_ok();
+
+    // the 24h volume drops the trades that are more than 23 hours old
+    setup.b_mock.set_block_timestamp(20 * hour);
+    setup.report_trade(&second_bonding, 50, MAX_MARKET_CAP / 2).assert_ok();
+    setup.b_mock.set_block_timestamp(33 * hour + 1);
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert_eq!(sc.get_volume_24h_view(managed_address!(&second_bonding)), managed_biguint!(250u64));
+            assert_eq!(sc.get_volume_24h_view(managed_address!(&first_bonding)), managed_biguint!(100u64));
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(34 * hour);
+    setup.report_trade(&second_bonding, 5, MAX_MARKET_CAP / 2).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert_eq!(sc.get_volume_24h_view(managed_address!(&second_bonding)), managed_biguint!(55u64));
+            assert_eq!(sc.get_volume_24h_view(managed_address!(&first_bonding)), managed_biguint!(0u64));
+        })
+        .assert_ok();
+
+    // a gap of more than two windows
+    setup.b_mock.set_block_timestamp(100 * hour);
+    setup.report_trade(&second_bonding, 7, MAX_MARKET_CAP / 2).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert_eq!(sc.get_volume_24h_view(managed_address!(&second_bonding)), managed_biguint!(7u64));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_ranking_size(0);
+        })
+        .assert_user_error("Invalid ranking size");
+    setup.report_trade(&user, 1, 1).assert_user_error("Not a pair SC");
+}
+
+#[test]
+fn ranking_stale_volume_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let first_bonding = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+    let second_bonding = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_ranking_size(1);
+        })
+        .assert_ok();
+
+    // the first bonding almost stops trading, its volume leaves the window but for the last trade
+    let hour = 3_600u64;
+    setup.b_mock.set_block_timestamp(hour);
+    setup.report_trade(&first_bonding, 1_000, MAX_MARKET_CAP / 2).assert_ok();
+    setup.b_mock.set_block_timestamp(20 * hour);
+    setup.report_trade(&first_bonding, 1, MAX_MARKET_CAP / 2).assert_ok();
+    setup.b_mock.set_block_timestamp(30 * hour);
+    setup.report_trade(&second_bonding, 500, MAX_MARKET_CAP / 10).assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let by_volume: Vec<_> = sc.get_top_by_volume().to_vec().iter().collect();
+            assert_eq!(by_volume.len(), 1);
+            assert_eq!(by_volume[0].sc_address, managed_address!(&second_bonding));
+            assert_eq!(by_volume[0].volume_24h, managed_biguint!(500u64));
+            assert_eq!(sc.get_volume_24h_view(managed_address!(&first_bonding)), managed_biguint!(1u64));
+
+            // market cap progress does not decay
+            let by_progress: Vec<_> = sc.get_top_by_market_cap_progress().to_vec().iter().collect();
+            assert_eq!(by_progress.len(), 1);
+            assert_eq!(by_progress[0].sc_address, managed_address!(&first_bonding));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ranking_lowest_member_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let first_bonding = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+    let second_bonding = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+    let third_bonding = setup.launch_token(&user, THIRD_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_ranking_size(2);
+        })
+        .assert_ok();
+
+    let hour = 3_600u64;
+    setup.b_mock.set_block_timestamp(hour);
+    setup.report_trade(&first_bonding, 100, 1).assert_ok();
+    setup.report_trade(&second_bonding, 300, 1).assert_ok();
+    // the lowest member trades above the other one
+    setup.report_trade(&first_bonding, 400, 1).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let lowest = sc.ranking_lowest(RankingKind::Volume).get();
+            assert_eq!(lowest.address, managed_address!(&second_bonding));
+            assert_eq!(lowest.score, managed_biguint!(300u64));
+            assert_eq!(lowest.refresh_hour, 25);
+        })
+        .assert_ok();
+
+    // a bonding outside the ranking has to beat the lowest member
+    setup.report_trade(&third_bonding, 200, 1).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert!(!sc.ranking(RankingKind::Volume).contains(&managed_address!(&third_bonding)));
+        })
+        .assert_ok();
+    setup.report_trade(&third_bonding, 150, 1).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let by_volume: Vec<_> = sc.get_top_by_volume().to_vec().iter().collect();
+            assert_eq!(by_volume.len(), 2);
+            assert_eq!(by_volume[0].sc_address, managed_address!(&first_bonding));
+            assert_eq!(by_volume[1].sc_address, managed_address!(&third_bonding));
+            assert_eq!(by_volume[1].volume_24h, managed_biguint!(350u64));
+        })
+        .assert_ok();
+
+    // once the members stopped trading for a whole window, any volume gets in
+    setup.b_mock.set_block_timestamp(30 * hour);
+    setup.report_trade(&second_bonding, 10, 1).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let by_volume: Vec<_> = sc.get_top_by_volume().to_vec().iter().collect();
+            assert_eq!(by_volume.len(), 1);
+            assert_eq!(by_volume[0].sc_address, managed_address!(&second_bonding));
+            assert_eq!(sc.ranking(RankingKind::Volume).len(), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ranking_graduated_bonding_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let dex_pair = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let first_bonding = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+    let second_bonding = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_ranking_size(1);
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(3_600);
+    setup.report_trade(&first_bonding, 1_000, MAX_MARKET_CAP / 2).assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&first_bonding, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.register_dex_pair(managed_address!(&dex_pair));
+        })
+        .assert_ok();
+
+    // the graduated bonding leaves both rankings, even if it still reports a trade
+    setup.report_trade(&first_bonding, 1_000, MAX_MARKET_CAP).assert_ok();
+    setup.report_trade(&second_bonding, 10, MAX_MARKET_CAP / 10).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let by_progress: Vec<_> = sc.get_top_by_market_cap_progress().to_vec().iter().collect();
+            assert_eq!(by_progress.len(), 1);
+            assert_eq!(by_progress[0].sc_address, managed_address!(&second_bonding));
+
+            let by_volume: Vec<_> = sc.get_top_by_volume().to_vec().iter().collect();
+            assert_eq!(by_volume.len(), 1);
+            assert_eq!(by_volume[0].sc_address, managed_address!(&second_bonding));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ranking_size_shrink_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let first_bonding = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+    let second_bonding = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+    let third_bonding = setup.launch_token(&user, THIRD_TOKEN_ID).address_ref().clone();
+
+    setup.b_mock.set_block_timestamp(3_600);
+    setup.report_trade(&first_bonding, 100, MAX_MARKET_CAP / 2).assert_ok();
+    setup.report_trade(&second_bonding, 300, MAX_MARKET_CAP / 10).assert_ok();
+    setup.report_trade(&third_bonding, 200, MAX_MARKET_CAP / 5).assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_ranking_size(1);
+            assert_eq!(sc.ranking(RankingKind::Volume).len(), 1);
+            assert_eq!(sc.ranking(RankingKind::MarketCapProgress).len(), 1);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let by_volume: Vec<_> = sc.get_top_by_volume().to_vec().iter().collect();
+            assert_eq!(by_volume.len(), 1);
+            assert_eq!(by_volume[0].sc_address, managed_address!(&second_bonding));
+
+            let by_progress: Vec<_> = sc.get_top_by_market_cap_progress().to_vec().iter().collect();
+            assert_eq!(by_progress.len(), 1);
+            assert_eq!(by_progress[0].sc_address, managed_address!(&first_bonding));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn batched_migrations_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+    for token_id in [FIRST_TOKEN_ID, SECOND_TOKEN_ID, THIRD_TOKEN_ID] {
+        let _ = setup.launch_token(&user, token_id);
+    }
+
+    // roll the storage back to what a master at version 0 holds
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.storage_version().set(0);
+            sc.total_launches().clear();
+            sc.all_bondings().clear();
+            sc.owed_tokens().clear();
+
+            sc.upgrade();
+            // funds recorded since the upgrade are already owed, no migration adds them again
+            sc.dex_liquidity_reserve(&managed_token_id!(FIRST_TOKEN_ID))
+                .set(managed_biguint!(100u64));
+            sc.add_owed_esdt(&managed_token_id!(FIRST_TOKEN_ID), &managed_biguint!(100u64));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.resume(managed_address!(&master_address));
+        })
+        .assert_user_error("Storage migrations are pending");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.run_migrations(0);
+        })
+        .assert_user_error("Batch size cannot be zero");
+
+    // v1, then the first two bondings of v2
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            assert_eq!(sc.run_migrations(3), 1);
+            assert_eq!(sc.total_launches().get(), 3);
+            assert_eq!(sc.all_bondings().len(), 2);
+            assert_eq!(sc.storage_version().get(), 1);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.resume(managed_address!(&master_address));
+        })
+        .assert_user_error("Storage migrations are pending");
+    // the last bonding of v2
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            assert_eq!(sc.run_migrations(10), 0);
+            assert_eq!(sc.all_bondings().len(), 3);
+            assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+            assert!(sc.migration_cursor().is_empty());
+
+            let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(sc.owed_balance(&token).get(), managed_biguint!(100u64));
+
+            assert_eq!(sc.run_migrations(1), 0);
+            sc.resume(managed_address!(&master_address));
+            assert!(sc.is_active());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn issuance_resolved_before_migrations_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+    let _ = setup.launch_token(&user, FIRST_TOKEN_ID);
+    let _ = setup.launch_token(&user, SECOND_TOKEN_ID);
+    let new_token_fee = setup.get_new_token_fee();
+    let pending = setup.start_issue(&user, new_token_fee);
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.storage_version().set(0);
+            sc.total_launches().clear();
+            sc.all_bondings().clear();
+            sc.upgrade();
+        })
+        .assert_ok();
+
+    // the issuance resolves while the migrations are still pending
+    let _ = setup
+        .b_mock
+        .prepare_deploy_from_sc(&master_address, setup.bonding_builder);
+    setup.resolve_issue(&pending, THIRD_TOKEN_ID, true).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert_eq!(sc.total_launches().get(), 1);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            assert_eq!(sc.run_migrations(10), 0);
+            assert_eq!(sc.total_launches().get(), 3);
+            assert_eq!(sc.all_bondings().len(), 3);
+
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.total_launches, 3);
+            assert_eq!(stats.active_bondings, 3);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn legacy_issuance_resolved_after_upgrade_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let master_address = setup.master_wrapper.address_ref().clone();
+    let launched = setup.start_issue(&user, NEW_TOKEN_FEE).into_legacy();
+    let refunded = setup.start_issue(&user, NEW_TOKEN_FEE).into_legacy();
+
+    // the issuances started on a master without the owed ledger
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.storage_version().set(0);
+            sc.owed_balance(&EgldOrEsdtTokenIdentifier::egld()).clear();
+            sc.owed_tokens().clear();
+            sc.upgrade();
+        })
+        .assert_ok();
+
+    let _ = setup
+        .b_mock
+        .prepare_deploy_from_sc(&master_address, setup.bonding_builder);
+    setup.resolve_issue(&launched, FIRST_TOKEN_ID, true).assert_ok();
+    setup.resolve_issue(&refunded, b"", false).assert_ok();
+
+    setup
+        .b_mock
+        .check_egld_balance(&user, &(rust_biguint!(USER_BALANCE) - rust_biguint!(NEW_TOKEN_FEE)));
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let bonding_address = sc.get_pair(managed_token_id!(FIRST_TOKEN_ID), managed_token_id!(WEGLD_TOKEN_ID));
+            assert!(!bonding_address.is_zero());
+            assert_eq!(sc.bonding_creator(&bonding_address).get(), managed_address!(&user));
+
+            let egld = EgldOrEsdtTokenIdentifier::egld();
+            assert_eq!(
+                sc.fees_accrued(FeeSource::LaunchFee, &egld).get(),
+                managed_biguint!(NEW_TOKEN_FEE - ISSUE_TOKEN_COST)
+            );
+            assert_eq!(sc.owed_balance(&egld).get(), managed_biguint!(0u64));
+            assert_eq!(sc.get_stray_balance(egld), managed_biguint!(0u64));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn bonding_paused_before_migrations_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let bonding_address = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.storage_version().set(0);
+            sc.total_launches().clear();
+            sc.upgrade();
+
+            // the legacy bonding is paused before its launch is counted
+            sc.pause(managed_address!(&bonding_address));
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.total_launches, 0);
+            assert_eq!(stats.paused_bondings, 1);
+            assert_eq!(stats.active_bondings, 0);
+
+            assert_eq!(sc.run_migrations(10), 0);
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.total_launches, 1);
+            assert_eq!(stats.paused_bondings, 1);
+            assert_eq!(stats.active_bondings, 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn dex_pair_for_token_test() {
+    let mut setup = setup();
+    let user = setup.user.clone();
+    let dex_pair = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let bonding_address = setup.launch_token(&user, FIRST_TOKEN_ID).address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert!(sc.get_dex_pair_for_token(managed_token_id!(FIRST_TOKEN_ID)).into_option().is_none());
+            assert!(sc.get_dex_pair_for_token(managed_token_id!(SECOND_TOKEN_ID)).into_option().is_none());
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.register_dex_pair(managed_address!(&dex_pair));
+        })
+        .assert_user_error("Not a pair SC");
+    setup
+        .b_mock
+        .execute_tx(&bonding_address, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.register_dex_pair(managed_address!(&dex_pair));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert!(
+                sc.get_dex_pair_for_token(managed_token_id!(FIRST_TOKEN_ID)).into_option()
+                    == Some(managed_address!(&dex_pair))
+            );
+            assert_eq!(sc.dex_pair(&managed_address!(&bonding_address)).get(), managed_address!(&dex_pair));
+            assert!(sc.get_dex_pair_for_token(managed_token_id!(SECOND_TOKEN_ID)).into_option().is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn lp_policy_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let fees_collector = setup.fees_collector.clone();
+    let dex_pair = setup.b_mock.create_user_account(&rust_biguint!(0u64));
+    let lp_amount = rust_biguint!(1_000u64);
+    let burn_address = Address::from(DEAD_ADDRESS);
+    setup
+        .b_mock
+        .create_account_raw(&burn_address, &rust_biguint!(0u64), None, None, None);
+
+    let launches = [
+        (FIRST_TOKEN_ID, LpPolicy::Burn, b"BURNLP-123456".as_slice()),
+        (SECOND_TOKEN_ID, LpPolicy::Lock, b"LOCKLP-123456".as_slice()),
+        (THIRD_TOKEN_ID, LpPolicy::Treasury, b"TREALP-123456".as_slice()),
+    ];
+    for (token_id, lp_policy, lp_token_id) in launches {
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.set_profile_graduation_policy(DEFAULT_LAUNCH_PROFILE, lp_policy, 10);
+            })
+            .assert_ok();
+        let bonding_address = setup.launch_token(&user, token_id).address_ref().clone();
+        setup
+            .b_mock
+            .execute_query(&setup.master_wrapper, |sc| {
+                assert!(sc.get_lp_lock_for_token(managed_token_id!(token_id)).into_option().is_none());
+            })
+            .assert_ok();
+
+        setup.b_mock.set_esdt_balance(&bonding_address, lp_token_id, &lp_amount);
+        setup
+            .b_mock
+            .execute_esdt_transfer(&bonding_address, &setup.master_wrapper, lp_token_id, 0, &lp_amount, |sc| {
+                sc.register_dex_pair(managed_address!(&dex_pair));
+            })
+            .assert_ok();
+        setup
+            .b_mock
+            .execute_tx(&bonding_address, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.register_dex_pair(managed_address!(&dex_pair));
+            })
+            .assert_user_error("Dex pair already registered");
+    }
+
+    // burned LP leaves the master for good
+    setup
+        .b_mock
+        .check_esdt_balance(&burn_address, b"BURNLP-123456", &lp_amount);
+    setup
+        .b_mock
+        .check_esdt_balance(setup.master_wrapper.address_ref(), b"BURNLP-123456", &rust_biguint!(0u64));
+
+    // treasury LP goes to the fees collector right away
+    setup
+        .b_mock
+        .check_esdt_balance(&fees_collector, b"TREALP-123456", &lp_amount);
+
+    // locked LP stays owed until the creator releases it
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let lp_lock = sc
+                .get_lp_lock_for_token(managed_token_id!(SECOND_TOKEN_ID))
+                .into_option()
+                .unwrap();
+            assert_eq!(lp_lock.token_id, managed_token_id!(b"LOCKLP-123456"));
+            assert_eq!(lp_lock.amount, managed_biguint!(1_000u64));
+            assert_eq!(lp_lock.unlock_epoch, 10);
+            assert!(sc.get_lp_lock_for_token(managed_token_id!(FIRST_TOKEN_ID)).into_option().is_none());
+            assert!(sc.get_lp_lock_for_token(managed_token_id!(THIRD_TOKEN_ID)).into_option().is_none());
+
+            let lp_token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(b"LOCKLP-123456"));
+            assert_eq!(sc.owed_balance(&lp_token).get(), managed_biguint!(1_000u64));
+            assert_eq!(sc.get_stray_balance(lp_token), managed_biguint!(0u64));
+            let burned_lp = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(b"BURNLP-123456"));
+            assert_eq!(sc.owed_balance(&burned_lp).get(), managed_biguint!(0u64));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.release_locked_lp(managed_token_id!(SECOND_TOKEN_ID));
+        })
+        .assert_user_error("LP tokens are still locked");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.release_locked_lp(managed_token_id!(SECOND_TOKEN_ID));
+        })
+        .assert_user_error("Only the token creator can perform this action");
+
+    setup.b_mock.set_block_epoch(10);
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.release_locked_lp(managed_token_id!(SECOND_TOKEN_ID));
+            let lp_token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(b"LOCKLP-123456"));
+            assert_eq!(sc.owed_balance(&lp_token).get(), managed_biguint!(0u64));
+            assert!(sc.get_lp_lock_for_token(managed_token_id!(SECOND_TOKEN_ID)).into_option().is_none());
+        })
+        .assert_ok();
+    setup.b_mock.check_esdt_balance(&user, b"LOCKLP-123456", &lp_amount);
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_graduation_policy(DEFAULT_LAUNCH_PROFILE, LpPolicy::Lock, 0);
+        })
+        .assert_user_error("Lock epochs cannot be zero");
+}
+
+#[test]
+fn launch_settings_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_max_start_delay(1_000);
+            sc.set_profile_anti_snipe(DEFAULT_LAUNCH_PROFILE, 5, managed_biguint!(1_000u64), 2_000);
+            sc.set_profile_curve(
+                DEFAULT_LAUNCH_PROFILE,
+                CurveParams {
+                    kind: CurveKind::Linear,
+                    initial_price: managed_biguint!(1_000u64),
+                    price_increment: managed_biguint!(10u64),
+                    growth_percent: 0,
+                    step_size: managed_biguint!(0u64),
+                },
+            );
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(100);
+    let bonding_wrapper = setup.launch_token_with(
+        &user,
+        FIRST_TOKEN_ID,
+        LaunchOptions {
+            start_timestamp: 600,
+            ..Default::default()
+        },
+    );
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.clear_profile_anti_snipe(DEFAULT_LAUNCH_PROFILE);
+            sc.clear_profile_curve(DEFAULT_LAUNCH_PROFILE);
+        })
+        .assert_ok();
+    let plain_bonding_wrapper = setup.launch_token(&user, SECOND_TOKEN_ID);
+
+    let check_settings = |sc: bonding_mock::ContractObj<DebugApi>, init_calls: u32| {
+        let settings = sc.launch_settings().get();
+        assert_eq!(settings.start_timestamp, 600);
+        let anti_snipe = settings.anti_snipe.unwrap();
+        assert_eq!(anti_snipe.window_blocks, 5);
+        assert_eq!(anti_snipe.max_buy_per_address, managed_biguint!(1_000u64));
+        assert_eq!(anti_snipe.fee_percent, 2_000);
+        assert!(settings.curve.unwrap().kind == CurveKind::Linear);
+        assert_eq!(sc.init_calls().get(), init_calls);
+    };
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| check_settings(sc, 1))
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&plain_bonding_wrapper, |sc| {
+            let settings = sc.launch_settings().get();
+            assert_eq!(settings.start_timestamp, 0);
+            assert!(settings.anti_snipe.is_none());
+            assert!(settings.curve.is_none());
+        })
+        .assert_ok();
+
+    // an upgrade initializes the bonding with the settings it launched with, even though
+    // the profile changed meanwhile
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.upgrade_pair_endpoint(managed_token_id!(FIRST_TOKEN_ID), managed_token_id!(WEGLD_TOKEN_ID));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| check_settings(sc, 2))
+        .assert_ok();
+}
+
+#[test]
+fn scheduled_launch_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let scheduled = |start_timestamp: u64| LaunchOptions {
+        start_timestamp,
+        ..Default::default()
+    };
+
+    setup.b_mock.set_block_timestamp(100);
+    setup
+        .call_new_token_with(&user, NEW_TOKEN_FEE, scheduled(600))
+        .assert_user_error("Scheduled launches are disabled");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_max_start_delay(1_000);
+        })
+        .assert_ok();
+    setup
+        .call_new_token_with(&user, NEW_TOKEN_FEE, scheduled(50))
+        .assert_user_error("Start timestamp must be in the future");
+    setup
+        .call_new_token_with(&user, NEW_TOKEN_FEE, scheduled(1_101))
+        .assert_user_error("Start timestamp is too far in the future");
+
+    let bonding_address = setup
+        .launch_token_with(&user, FIRST_TOKEN_ID, scheduled(600))
+        .address_ref()
+        .clone();
+    let _ = setup.launch_token(&user, SECOND_TOKEN_ID);
+
+    let mut launch_fee = 0u64;
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let upcoming: Vec<_> = sc.get_upcoming_launches().into_iter().collect();
+            assert_eq!(upcoming.len(), 1);
+            assert_eq!(upcoming[0].sc_address, managed_address!(&bonding_address));
+            assert_eq!(upcoming[0].token_id, managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(upcoming[0].creator, managed_address!(&user));
+            assert_eq!(upcoming[0].start_timestamp, 600);
+
+            launch_fee = sc
+                .launch_fee_escrow(&managed_address!(&bonding_address))
+                .get()
+                .to_u64()
+                .unwrap();
+            assert!(launch_fee > 0);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.release_launch_fee(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Launch has not started yet");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.release_launch_fee(managed_token_id!(SECOND_TOKEN_ID));
+        })
+        .assert_user_error("Launch is not scheduled");
+
+    // once trading started the fee is no longer refundable and anyone can release it
+    setup.b_mock.set_block_timestamp(600);
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.cancel_scheduled_launch(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Launch has already started");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            assert!(sc.get_upcoming_launches().is_empty());
+            sc.release_launch_fee(managed_token_id!(FIRST_TOKEN_ID));
+
+            let egld = EgldOrEsdtTokenIdentifier::egld();
+            assert!(sc.launch_fee_escrow(&managed_address!(&bonding_address)).is_empty());
+            assert_eq!(
+                sc.fees_accrued(FeeSource::ScheduledLaunchFee, &egld).get(),
+                managed_biguint!(launch_fee)
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.release_launch_fee(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Launch is not scheduled");
+}
+
+#[test]
+fn cancel_scheduled_launch_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let treasury = setup.fees_collector.clone();
+    let burn_address = Address::from(DEAD_ADDRESS);
+    setup
+        .b_mock
+        .create_account_raw(&burn_address, &rust_biguint!(0u64), None, None, None);
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_max_start_delay(1_000);
+            sc.set_cancel_refund_percent(5_000);
+            sc.set_profile_supply_allocation(
+                DEFAULT_LAUNCH_PROFILE,
+                SupplyAllocation {
+                    dex_reserve_percent: 1_000,
+                    airdrop_percent: 0,
+                    treasury_percent: 0,
+                    treasury_address: managed_address!(&treasury),
+                    dev_percent: 0,
+                    dev_cliff_duration: 0,
+                    dev_vesting_duration: 0,
+                },
+            );
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(100);
+    let bonding_wrapper = setup.launch_token_with(
+        &user,
+        FIRST_TOKEN_ID,
+        LaunchOptions {
+            start_timestamp: 600,
+            ..Default::default()
+        },
+    );
+    let bonding_address = bonding_wrapper.address_ref().clone();
+
+    let mut launch_fee = 0u64;
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            launch_fee = sc
+                .launch_fee_escrow(&managed_address!(&bonding_address))
+                .get()
+                .to_u64()
+                .unwrap();
+        })
+        .assert_ok();
+
+    // only the creator can cancel
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.cancel_scheduled_launch(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Only the token creator can perform this action");
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.cancel_scheduled_launch(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_ok();
+
+    // the curve supply and the DEX reserve go to the dead address
+    setup
+        .b_mock
+        .check_esdt_balance(&burn_address, FIRST_TOKEN_ID, &rust_biguint!(TOKEN_SUPPLY));
+    setup
+        .b_mock
+        .check_esdt_balance(setup.master_wrapper.address_ref(), FIRST_TOKEN_ID, &rust_biguint!(0u64));
+    setup
+        .b_mock
+        .execute_query(&bonding_wrapper, |sc| {
+            assert!(!sc.active().get());
+            assert_eq!(sc.token_reserve().get(), managed_biguint!(0u64));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let bonding = managed_address!(&bonding_address);
+            assert!(sc.cancelled_launch(&bonding).get());
+            assert_eq!(
+                sc.cancelled_launch_burned_supply(&bonding).get(),
+                managed_biguint!(TOKEN_SUPPLY)
+            );
+            assert!(sc.dex_liquidity_reserve(&managed_token_id!(FIRST_TOKEN_ID)).is_empty());
+            assert_eq!(
+                sc.owed_balance(&EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(FIRST_TOKEN_ID))).get(),
+                managed_biguint!(0u64)
+            );
+
+            let stats = sc.get_platform_stats();
+            assert_eq!(stats.paused_bondings, 1);
+            assert_eq!(stats.total_refunds, managed_biguint!(launch_fee / 2));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.cancel_scheduled_launch(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Launch is not scheduled");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.resume(managed_address!(&bonding_address));
+        })
+        .assert_user_error("Launch was cancelled");
+}
+
+#[test]
+fn anti_snipe_profile_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_anti_snipe(1, 5, managed_biguint!(1_000u64), 2_000);
+        })
+        .assert_user_error("Launch profile does not exist");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.add_launch_profile(1);
+            sc.set_profile_anti_snipe(1, 5, managed_biguint!(1_000u64), 2_000);
+        })
+        .assert_ok();
+    let invalid_configs = [
+        (0, 1_000u64, 2_000, "Anti-snipe window cannot be zero"),
+        (5, 0, 2_000, "Max buy per address cannot be zero"),
+        (5, 1_000, 10_001, "Anti-snipe fee too high"),
+    ];
+    for (window_blocks, max_buy_per_address, fee_percent, error) in invalid_configs {
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+                sc.set_profile_anti_snipe(1, window_blocks, managed_biguint!(max_buy_per_address), fee_percent);
+            })
+            .assert_user_error(error);
+    }
+
+    // only bondings launched with the profile get the window
+    let protected_address = setup
+        .launch_token_with(
+            &user,
+            FIRST_TOKEN_ID,
+            LaunchOptions {
+                launch_profile: Some(1),
+                ..Default::default()
+            },
+        )
+        .address_ref()
+        .clone();
+    let plain_address = setup.launch_token(&user, SECOND_TOKEN_ID).address_ref().clone();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let anti_snipe = sc.bonding_anti_snipe(&managed_address!(&protected_address)).get();
+            assert_eq!(anti_snipe.window_blocks, 5);
+            assert_eq!(anti_snipe.max_buy_per_address, managed_biguint!(1_000u64));
+            assert_eq!(anti_snipe.fee_percent, 2_000);
+            assert!(sc.bonding_anti_snipe(&managed_address!(&plain_address)).is_empty());
+        })
+        .assert_ok();
+
+    // clearing the profile does not touch bondings already launched
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.clear_profile_anti_snipe(1);
+            assert!(sc.profile_anti_snipe(1).is_empty());
+            assert!(!sc.bonding_anti_snipe(&managed_address!(&protected_address)).is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn remove_launch_profile_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let treasury = setup.fees_collector.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.add_launch_profile(1);
+            sc.set_profile_anti_snipe(1, 5, managed_biguint!(1_000u64), 2_000);
+            sc.set_profile_curve(
+                1,
+                CurveParams {
+                    kind: CurveKind::Linear,
+                    initial_price: managed_biguint!(1_000u64),
+                    price_increment: managed_biguint!(10u64),
+                    growth_percent: 0,
+                    step_size: managed_biguint!(0u64),
+                },
+            );
+            sc.set_profile_supply_allocation(
+                1,
+                SupplyAllocation {
+                    dex_reserve_percent: 0,
+                    airdrop_percent: 0,
+                    treasury_percent: 1_000,
+                    treasury_address: managed_address!(&treasury),
+                    dev_percent: 0,
+                    dev_cliff_duration: 0,
+                    dev_vesting_duration: 0,
+                },
+            );
+            sc.set_profile_graduation_policy(1, LpPolicy::Lock, 10);
+            sc.set_profile_creator_buy_vesting(1, 100, 1_000);
+        })
+        .assert_ok();
+    let bonding_address = setup
+        .launch_token_with(
+            &user,
+            FIRST_TOKEN_ID,
+            LaunchOptions {
+                launch_profile: Some(1),
+                ..Default::default()
+            },
+        )
+        .address_ref()
+        .clone();
+
+    // a profile added again under the same id starts without settings
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.remove_launch_profile(1);
+            sc.add_launch_profile(1);
+
+            assert!(sc.profile_anti_snipe(1).is_empty());
+            assert!(sc.profile_curve(1).is_empty());
+            assert!(sc.profile_supply_allocation(1).is_empty());
+            assert!(sc.profile_graduation_policy(1).is_empty());
+            assert!(sc.profile_creator_buy_vesting(1).is_empty());
+
+            let bonding_address = managed_address!(&bonding_address);
+            assert!(!sc.bonding_anti_snipe(&bonding_address).is_empty());
+            assert!(!sc.bonding_graduation_policy(&bonding_address).is_empty());
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.remove_launch_profile(2);
+        })
+        .assert_user_error("Profile does not exist");
+}
+
+#[test]
+fn curve_cost_test() {
+    let mut setup = setup();
+    let one_token = 1_000_000_000_000_000_000u64;
+    let curve = |kind: CurveKind, price_increment: u64, growth_percent: u64| CurveParams::<DebugApi> {
+        kind,
+        initial_price: managed_biguint!(one_token),
+        price_increment: managed_biguint!(price_increment),
+        growth_percent,
+        step_size: managed_biguint!(one_token),
+    };
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            // price 1 + sold, so 1 + 1/2 for the first token and 2 + 1/2 for the second
+            let linear = curve(CurveKind::Linear, one_token, 0);
+            assert_eq!(
+                sc.curve_cost(&linear, &managed_biguint!(0u64), &managed_biguint!(one_token)),
+                managed_biguint!(one_token * 3 / 2)
+            );
+            assert_eq!(
+                sc.curve_cost(&linear, &managed_biguint!(one_token), &managed_biguint!(one_token)),
+                managed_biguint!(one_token * 5 / 2)
+            );
+
+            // prices 1, 2, 3 for the first three tokens
+            let stepwise = curve(CurveKind::Stepwise, one_token, 0);
+            assert_eq!(
+                sc.curve_cost(&stepwise, &managed_biguint!(0u64), &managed_biguint!(one_token * 5 / 2)),
+                managed_biguint!(one_token * 9 / 2)
+            );
+            assert_eq!(
+                sc.curve_cost(&stepwise, &managed_biguint!(one_token / 2), &managed_biguint!(one_token)),
+                managed_biguint!(one_token * 3 / 2)
+            );
+
+            // prices 1, 1.1, 1.21 for the first three tokens
+            let exponential = curve(CurveKind::Exponential, 0, 1_000);
+            assert_eq!(
+                sc.curve_cost(&exponential, &managed_biguint!(0u64), &managed_biguint!(3 * one_token)),
+                managed_biguint!(one_token / 100 * 331)
+            );
+            assert_eq!(
+                sc.curve_cost(&exponential, &managed_biguint!(one_token), &managed_biguint!(0u64)),
+                managed_biguint!(0u64)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn curve_steps_after_supply_raise_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_curve(
+                DEFAULT_LAUNCH_PROFILE,
+                CurveParams {
+                    kind: CurveKind::Stepwise,
+                    initial_price: managed_biguint!(1_000u64),
+                    price_increment: managed_biguint!(10u64),
+                    growth_percent: 0,
+                    step_size: managed_biguint!(TOKEN_SUPPLY / 1_000),
+                },
+            );
+            sc.set_token_supply(managed_biguint!(TOKEN_SUPPLY * 10));
+        })
+        .assert_ok();
+
+    // only the first thousand steps of the profile curve can still be priced
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            sc.simulate_buy_cost(
+                DEFAULT_LAUNCH_PROFILE,
+                managed_biguint!(0u64),
+                managed_biguint!(TOKEN_SUPPLY * 2),
+            );
+        })
+        .assert_user_error("Step size too small for the token supply");
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            sc.simulate_buy_cost(
+                DEFAULT_LAUNCH_PROFILE,
+                managed_biguint!(0u64),
+                managed_biguint!(TOKEN_SUPPLY),
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn simulate_buy_cost_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let treasury = setup.fees_collector.clone();
+
+    // constant product seeded with the initial virtual liquidity and the whole supply
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let cost = sc.simulate_buy_cost(
+                DEFAULT_LAUNCH_PROFILE,
+                managed_biguint!(0u64),
+                managed_biguint!(TOKEN_SUPPLY / 2),
+            );
+            assert_eq!(cost, managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY));
+        })
+        .assert_ok();
+
+    // only half of the supply goes to the curve
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_supply_allocation(
+                DEFAULT_LAUNCH_PROFILE,
+                SupplyAllocation {
+                    dex_reserve_percent: 5_000,
+                    airdrop_percent: 0,
+                    treasury_percent: 0,
+                    treasury_address: managed_address!(&treasury),
+                    dev_percent: 0,
+                    dev_cliff_duration: 0,
+                    dev_vesting_duration: 0,
+                },
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let cost = sc.simulate_buy_cost(
+                DEFAULT_LAUNCH_PROFILE,
+                managed_biguint!(0u64),
+                managed_biguint!(TOKEN_SUPPLY / 4),
+            );
+            assert_eq!(cost, managed_biguint!(INITIAL_VIRTUAL_LIQUIDITY));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            sc.simulate_buy_cost(
+                DEFAULT_LAUNCH_PROFILE,
+                managed_biguint!(TOKEN_SUPPLY / 4),
+                managed_biguint!(TOKEN_SUPPLY / 4),
+            );
+        })
+        .assert_user_error("Not enough tokens on the curve");
+}
+
+#[test]
+fn buy_quote_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let one_token = 1_000_000_000_000_000_000u64;
+    let step_size = TOKEN_SUPPLY / 100;
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            let profiles = [
+                (1, CurveKind::Linear, one_token, 0),
+                (2, CurveKind::Exponential, 0, 1_000),
+                (3, CurveKind::Stepwise, one_token, 0),
+            ];
+            for (profile_id, kind, price_increment, growth_percent) in profiles {
+                sc.add_launch_profile(profile_id);
+                sc.set_profile_curve(
+                    profile_id,
+                    CurveParams {
+                        kind,
+                        initial_price: managed_biguint!(one_token),
+                        price_increment: managed_biguint!(price_increment),
+                        growth_percent,
+                        step_size: managed_biguint!(step_size),
+                    },
+                );
+            }
+        })
+        .assert_ok();
+
+    // the constant product bonding is fresh, the others already sold the 1% creator buy
+    let launches: [(&[u8], u32, u64); 4] = [
+        (FIRST_TOKEN_ID, DEFAULT_LAUNCH_PROFILE, 0),
+        (SECOND_TOKEN_ID, 1, TOKEN_SUPPLY / 100),
+        (THIRD_TOKEN_ID, 2, TOKEN_SUPPLY / 100),
+        (b"STEP-123456", 3, TOKEN_SUPPLY / 100),
+    ];
+    for (token_id, launch_profile, sold) in launches {
+        let bonding_wrapper = setup.launch_token_with(
+            &user,
+            token_id,
+            LaunchOptions {
+                launch_profile: Some(launch_profile),
+                token_creator_buy: sold > 0,
+                ..Default::default()
+            },
+        );
+        let bonding_address = bonding_wrapper.address_ref().clone();
+
+        // the debugger cannot run the read-only call of the view, so the reserves it would
+        // read are taken from the bonding
+        let mut reserves = (0u64, 0u64);
+        setup
+            .b_mock
+            .execute_query(&bonding_wrapper, |sc| {
+                let pair_data = sc.get_pair_data();
+                reserves = (
+                    pair_data.first_token_reserve.to_u64().unwrap(),
+                    pair_data.second_token_reserve.to_u64().unwrap(),
+                );
+            })
+            .assert_ok();
+        let (token_reserve, quote_reserve) = reserves;
+        let quote = |sc: &master::ContractObj<DebugApi>, amount: u64| {
+            sc.buy_quote(
+                &managed_address!(&bonding_address),
+                &managed_biguint!(token_reserve),
+                &managed_biguint!(quote_reserve),
+                &managed_biguint!(amount),
+            )
+        };
+
+        setup
+            .b_mock
+            .execute_query(&setup.master_wrapper, |sc| {
+                for amount in [1u64, step_size / 2, 3 * step_size, TOKEN_SUPPLY / 2] {
+                    let quote = quote(&sc, amount);
+                    let simulated = sc.simulate_buy_cost(
+                        launch_profile,
+                        managed_biguint!(sold),
+                        managed_biguint!(amount),
+                    );
+                    assert_eq!(quote, simulated);
+                }
+                assert!(quote(&sc, step_size) > 0);
+            })
+            .assert_ok();
+
+        // the last tokens on the curve cannot be bought
+        setup
+            .b_mock
+            .execute_query(&setup.master_wrapper, |sc| {
+                quote(&sc, TOKEN_SUPPLY - sold);
+            })
+            .assert_user_error("Not enough tokens on the curve");
+    }
+
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            sc.get_buy_quote(managed_token_id!(b"NONE-123456"), managed_biguint!(1u64));
+        })
+        .assert_user_error("Bonding does not exists");
+}
+
+#[test]
+fn creator_buy_vesting_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let creator_buy = LaunchOptions {
+        token_creator_buy: true,
+        ..Default::default()
+    };
+    let bought = TOKEN_SUPPLY / 100;
+
+    // without a vesting the creator gets the tokens right away
+    setup.launch_token_with(&user, FIRST_TOKEN_ID, creator_buy);
+    setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(bought));
+
+    setup.b_mock.set_block_timestamp(1_000);
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_creator_buy_vesting(DEFAULT_LAUNCH_PROFILE, 100, 1_000);
+        })
+        .assert_ok();
+    let vested_bonding = setup.launch_token_with(&user, SECOND_TOKEN_ID, creator_buy);
+    setup
+        .b_mock
+        .check_esdt_balance(&user, SECOND_TOKEN_ID, &rust_biguint!(0u64));
+    // the bonding still knows the creator, only the bought tokens go to the master
+    setup
+        .b_mock
+        .execute_query(&vested_bonding, |sc| {
+            assert_eq!(sc.creator().get(), managed_address!(&user));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(setup.master_wrapper.address_ref(), SECOND_TOKEN_ID, &rust_biguint!(bought));
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let amounts = sc.get_vesting_amounts(managed_token_id!(SECOND_TOKEN_ID));
+            assert_eq!(amounts.locked, managed_biguint!(bought));
+            assert_eq!(amounts.claimable, managed_biguint!(0u64));
+            let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(SECOND_TOKEN_ID));
+            assert_eq!(sc.owed_balance(&token).get(), managed_biguint!(bought));
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(1_099);
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_vested_tokens(managed_token_id!(SECOND_TOKEN_ID));
+        })
+        .assert_user_error("Nothing to claim");
+
+    setup.b_mock.set_block_timestamp(1_600);
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            assert_eq!(
+                sc.claim_vested_tokens(managed_token_id!(SECOND_TOKEN_ID)),
+                managed_biguint!(bought / 2)
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&user, SECOND_TOKEN_ID, &rust_biguint!(bought / 2));
+
+    // a launch without a creator buy records no vesting
+    setup.launch_token(&user, THIRD_TOKEN_ID);
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            assert_eq!(sc.get_vesting_schedules(managed_token_id!(THIRD_TOKEN_ID)).len(), 0);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_creator_buy_vesting(DEFAULT_LAUNCH_PROFILE, 0, 0);
+        })
+        .assert_user_error("Lock duration cannot be zero");
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_profile_creator_buy_vesting(DEFAULT_LAUNCH_PROFILE, 0, u64::MAX);
+        })
+        .assert_user_error("Lock duration too long");
+}
+
+#[test]
+fn lock_creator_tokens_test() {
+    let mut setup = setup();
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let bought = TOKEN_SUPPLY / 100;
+    setup.launch_token_with(
+        &user,
+        FIRST_TOKEN_ID,
+        LaunchOptions {
+            token_creator_buy: true,
+            ..Default::default()
+        },
+    );
+
+    setup
+        .b_mock
+        .set_esdt_balance(&owner, FIRST_TOKEN_ID, &rust_biguint!(bought));
+    setup
+        .b_mock
+        .execute_esdt_transfer(&owner, &setup.master_wrapper, FIRST_TOKEN_ID, 0, &rust_biguint!(bought), |sc| {
+            sc.lock_creator_tokens(100, 1_000);
+        })
+        .assert_user_error("Only the token creator can perform this action");
+    setup
+        .b_mock
+        .execute_esdt_transfer(&user, &setup.master_wrapper, FIRST_TOKEN_ID, 0, &rust_biguint!(bought), |sc| {
+            sc.lock_creator_tokens(0, 0);
+        })
+        .assert_user_error("Lock duration cannot be zero");
+    setup
+        .b_mock
+        .execute_esdt_transfer(&user, &setup.master_wrapper, FIRST_TOKEN_ID, 0, &rust_biguint!(bought), |sc| {
+            sc.lock_creator_tokens(u64::MAX, 0);
+        })
+        .assert_user_error("Lock duration too long");
+
+    setup.b_mock.set_block_timestamp(1_000);
+    setup
+        .b_mock
+        .execute_esdt_transfer(&user, &setup.master_wrapper, FIRST_TOKEN_ID, 0, &rust_biguint!(bought), |sc| {
+            sc.lock_creator_tokens(100, 1_000);
+
+            let schedules = sc.get_vesting_schedules(managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(schedules.len(), 1);
+            let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(sc.owed_balance(&token).get(), managed_biguint!(bought));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(0u64));
+
+    // nothing vests before the cliff
+    setup.b_mock.set_block_timestamp(1_099);
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_vested_tokens(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Nothing to claim");
+
+    setup.b_mock.set_block_timestamp(1_350);
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.claim_vested_tokens(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_user_error("Only the token creator can perform this action");
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.master_wrapper, &rust_biguint!(0u64), |sc| {
+            assert_eq!(
+                sc.claim_vested_tokens(managed_token_id!(FIRST_TOKEN_ID)),
+                managed_biguint!(bought / 4)
+            );
+
+            let amounts = sc.get_vesting_amounts(managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(amounts.locked, managed_biguint!(bought - bought / 4));
+            assert_eq!(amounts.claimable, managed_biguint!(0u64));
+            assert_eq!(amounts.claimed, managed_biguint!(bought / 4));
+            let token = EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(FIRST_TOKEN_ID));
+            assert_eq!(sc.owed_balance(&token).get(), managed_biguint!(bought - bought / 4));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&user, FIRST_TOKEN_ID, &rust_biguint!(bought / 4));
+}
+
+#[test]
+fn vested_amount_test() {
+    let mut setup = setup();
+    setup
+        .b_mock
+        .execute_query(&setup.master_wrapper, |sc| {
+            let schedule = VestingSchedule::<DebugApi> {
+                total_amount: managed_biguint!(1_000u64),
+                claimed_amount: managed_biguint!(0u64),
+                start_timestamp: 100,
+                cliff_duration: 50,
+                vesting_duration: 200,
+            };
+            // nothing before the cliff ends, then linear over the vesting duration
+            assert_eq!(sc.get_vested_amount(&schedule, 0), managed_biguint!(0u64));
+            assert_eq!(sc.get_vested_amount(&schedule, 149), managed_biguint!(0u64));
+            assert_eq!(sc.get_vested_amount(&schedule, 150), managed_biguint!(0u64));
+            assert_eq!(sc.get_vested_amount(&schedule, 151), managed_biguint!(5u64));
+            assert_eq!(sc.get_vested_amount(&schedule, 250), managed_biguint!(500u64));
+            assert_eq!(sc.get_vested_amount(&schedule, 350), managed_biguint!(1_000u64));
+            assert_eq!(sc.get_vested_amount(&schedule, u64::MAX), managed_biguint!(1_000u64));
+
+            // a pure cliff releases everything at once
+            let cliff_only = VestingSchedule::<DebugApi> {
+                vesting_duration: 0,
+                ..schedule
+            };
+            assert_eq!(sc.get_vested_amount(&cliff_only, 149), managed_biguint!(0u64));
+            assert_eq!(sc.get_vested_amount(&cliff_only, 150), managed_biguint!(1_000u64));
+
+            // a cliff ending past the last timestamp never releases anything
+            let endless_cliff = VestingSchedule::<DebugApi> {
+                cliff_duration: u64::MAX - 10,
+                ..cliff_only
+            };
+            assert_eq!(sc.get_vested_amount(&endless_cliff, 150), managed_biguint!(0u64));
+            assert_eq!(sc.get_vested_amount(&endless_cliff, u64::MAX - 1), managed_biguint!(0u64));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,105 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Endpoints:                           78
+// Async Callback (empty):               1
+// Total number of exported functions:  80
+
+#![no_std]
+#![allow(internal_features)]
+#![feature(lang_items)]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::external_view_init! {}
+
+multiversx_sc_wasm_adapter::external_view_endpoints! {
+    master
+    (
+        getState => state
+        getTokenSupply => token_supply
+        getNewTokenFee => new_token_fee
+        getBondingTemplateAddress => pair_template_address
+        getGasSchedule => get_gas_schedule
+        getNewTokenGasLimit => get_new_token_gas_limit
+        getConfig => get_config
+        getAllBondings => all_bondings
+        getBondingCreator => bonding_creator
+        isAllowlisted => is_allowlisted
+        isBlocklisted => is_blocklisted
+        isAllowlistEnabled => allowlist_enabled
+        getAllowlist => creator_allowlist
+        getBlocklist => creator_blocklist
+        getBondingParams => get_bonding_params
+        getDexPair => dex_pair
+        getAllBondingMetadata => get_all_pair_contract_metadata
+        getAllBondingData => get_all_pair_contract_data
+        getTokenCreator => get_token_creator
+        getPendingCreator => get_pending_creator
+        getClaimableCreatorFees => get_claimable_creator_fees
+        getCreatorFeesHistory => get_creator_fees_history
+        getCreatorFeeShare => creator_fee_share
+        getDexPairForToken => get_dex_pair_for_token
+        getLpLockForToken => get_lp_lock_for_token
+        getProfileGraduationPolicy => profile_graduation_policy
+        getBondingGraduationPolicy => bonding_graduation_policy
+        getLpLock => lp_lock
+        getBurnedLp => burned_lp
+        getTokenMetadata => token_metadata
+        isTokenMetadataLocked => token_metadata_locked
+        getMigrationCursor => migration_cursor
+        getStorageVersion => storage_version
+        getLaunchProfiles => launch_profiles
+        getBondingLaunchProfile => bonding_launch_profile
+        getProfileAntiSnipe => profile_anti_snipe
+        getBondingAntiSnipe => bonding_anti_snipe
+        getTopByMarketCapProgress => get_top_by_market_cap_progress
+        getTopByVolume => get_top_by_volume
+        getVolume24h => get_volume_24h_view
+        getLastMarketCap => last_market_cap
+        getRankingSize => ranking_size
+        getRemainingLaunchQuota => get_remaining_launch_quota
+        getLaunchWindowRounds => launch_window_rounds
+        getMaxLaunchesPerWindow => max_launches_per_window
+        getMaxLaunchesPerBlock => max_launches_per_block
+        getRateLimitExempt => rate_limit_exempt
+        getUpcomingLaunches => get_upcoming_launches
+        getMaxStartDelay => max_start_delay
+        getCancelRefundPercent => cancel_refund_percent
+        getLaunchStartTimestamp => launch_start_timestamp
+        isLaunchCancelled => cancelled_launch
+        getCancelledLaunchBurnedSupply => cancelled_launch_burned_supply
+        getPlatformStats => get_platform_stats
+        getVestingSchedules => get_vesting_schedules
+        getVestingAmounts => get_vesting_amounts
+        getProfileCreatorBuyVesting => profile_creator_buy_vesting
+        getProfileSupplyAllocation => profile_supply_allocation
+        getDexLiquidityReserve => dex_liquidity_reserve
+        getAirdropReserve => airdrop_reserve
+        getAirdrop => airdrop
+        isAirdropClaimed => airdrop_claimed
+        getTokenAirdrops => token_airdrops
+        getBuyQuote => get_buy_quote
+        simulateBuyCost => simulate_buy_cost
+        getProfileCurve => profile_curve
+        getBondingCurve => bonding_curve
+        getBondingCurveSupply => bonding_curve_supply
+        getBondingParamPush => get_bonding_param_push
+        getStrayBalance => get_stray_balance
+        getFeeAccounts => get_fee_accounts
+        isFeesPullMode => fees_pull_mode
+        getFeeTokens => fee_tokens
+        getFeesAccrued => fees_accrued
+        getFeesWithdrawn => fees_withdrawn
+        getWithdrawableFees => withdrawable_fees
+        getOwedTokens => owed_tokens
+        getOwedBalance => owed_balance
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
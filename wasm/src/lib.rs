@@ -0,0 +1,170 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Endpoints:                          144
+// Async Callback:                       1
+// Total number of exported functions: 146
+
+#![no_std]
+#![allow(internal_features)]
+#![feature(lang_items)]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    master
+    (
+        init => init
+        upgrade => upgrade
+        pause => pause
+        resume => resume
+        removeLaunchProfile => remove_launch_profile
+        setJeetDexRouter => set_jeetdex_router
+        newToken => create_new_token_endpoint
+        upgradeToken => upgrade_pair_endpoint
+        getState => state
+        setFeesCollector => set_fees_collector
+        setIVL => set_initial_virtual_liquidity
+        getTokenSupply => token_supply
+        setTokenSupply => set_token_supply
+        getNewTokenFee => new_token_fee
+        setNewTokenFee => set_new_token_fee
+        setMaxMarketCap => set_max_market_cap
+        getBondingTemplateAddress => pair_template_address
+        setBondingTemplateAddress => set_bonding_template_address
+        setGasSchedule => set_gas_schedule
+        getGasSchedule => get_gas_schedule
+        getNewTokenGasLimit => get_new_token_gas_limit
+        getConfig => get_config
+        setConfig => set_config
+        getAllBondings => all_bondings
+        getBondingCreator => bonding_creator
+        setAllowlistEnabled => set_allowlist_enabled
+        addToAllowlist => add_to_allowlist
+        removeFromAllowlist => remove_from_allowlist
+        addToBlocklist => add_to_blocklist
+        removeFromBlocklist => remove_from_blocklist
+        isAllowlisted => is_allowlisted
+        isBlocklisted => is_blocklisted
+        isAllowlistEnabled => allowlist_enabled
+        getAllowlist => creator_allowlist
+        getBlocklist => creator_blocklist
+        getBondingParams => get_bonding_params
+        getDexPair => dex_pair
+        getAllBondingMetadata => get_all_pair_contract_metadata
+        getAllBondingData => get_all_pair_contract_data
+        proposeCreatorTransfer => propose_creator_transfer
+        cancelCreatorTransfer => cancel_creator_transfer
+        acceptCreatorTransfer => accept_creator_transfer
+        getTokenCreator => get_token_creator
+        getPendingCreator => get_pending_creator
+        setCreatorFeeShare => set_creator_fee_share
+        depositSwapFees => deposit_swap_fees
+        claimCreatorFees => claim_creator_fees
+        getClaimableCreatorFees => get_claimable_creator_fees
+        getCreatorFeesHistory => get_creator_fees_history
+        getCreatorFeeShare => creator_fee_share
+        registerDexPair => register_dex_pair
+        setDexPairForBonding => set_dex_pair_for_bonding
+        setProfileGraduationPolicy => set_profile_graduation_policy
+        clearProfileGraduationPolicy => clear_profile_graduation_policy
+        releaseLockedLp => release_locked_lp
+        getDexPairForToken => get_dex_pair_for_token
+        getLpLockForToken => get_lp_lock_for_token
+        getProfileGraduationPolicy => profile_graduation_policy
+        getBondingGraduationPolicy => bonding_graduation_policy
+        getLpLock => lp_lock
+        getBurnedLp => burned_lp
+        setTokenMetadata => set_token_metadata
+        moderateTokenMetadata => moderate_token_metadata
+        getTokenMetadata => token_metadata
+        isTokenMetadataLocked => token_metadata_locked
+        runMigrations => run_migrations
+        getMigrationCursor => migration_cursor
+        getStorageVersion => storage_version
+        addLaunchProfile => add_launch_profile
+        getLaunchProfiles => launch_profiles
+        getBondingLaunchProfile => bonding_launch_profile
+        setProfileAntiSnipe => set_profile_anti_snipe
+        clearProfileAntiSnipe => clear_profile_anti_snipe
+        getProfileAntiSnipe => profile_anti_snipe
+        getBondingAntiSnipe => bonding_anti_snipe
+        reportTrade => report_trade
+        setRankingSize => set_ranking_size
+        getTopByMarketCapProgress => get_top_by_market_cap_progress
+        getTopByVolume => get_top_by_volume
+        getVolume24h => get_volume_24h_view
+        getLastMarketCap => last_market_cap
+        getRankingSize => ranking_size
+        setLaunchRateLimits => set_launch_rate_limits
+        addRateLimitExempt => add_rate_limit_exempt
+        removeRateLimitExempt => remove_rate_limit_exempt
+        getRemainingLaunchQuota => get_remaining_launch_quota
+        getLaunchWindowRounds => launch_window_rounds
+        getMaxLaunchesPerWindow => max_launches_per_window
+        getMaxLaunchesPerBlock => max_launches_per_block
+        getRateLimitExempt => rate_limit_exempt
+        setMaxStartDelay => set_max_start_delay
+        setCancelRefundPercent => set_cancel_refund_percent
+        releaseLaunchFee => release_launch_fee
+        cancelScheduledLaunch => cancel_scheduled_launch
+        getUpcomingLaunches => get_upcoming_launches
+        getMaxStartDelay => max_start_delay
+        getCancelRefundPercent => cancel_refund_percent
+        getLaunchStartTimestamp => launch_start_timestamp
+        isLaunchCancelled => cancelled_launch
+        getCancelledLaunchBurnedSupply => cancelled_launch_burned_supply
+        getPlatformStats => get_platform_stats
+        setProfileCreatorBuyVesting => set_profile_creator_buy_vesting
+        clearProfileCreatorBuyVesting => clear_profile_creator_buy_vesting
+        lockCreatorTokens => lock_creator_tokens
+        claimVestedTokens => claim_vested_tokens
+        getVestingSchedules => get_vesting_schedules
+        getVestingAmounts => get_vesting_amounts
+        getProfileCreatorBuyVesting => profile_creator_buy_vesting
+        setProfileSupplyAllocation => set_profile_supply_allocation
+        clearProfileSupplyAllocation => clear_profile_supply_allocation
+        claimDexReserve => claim_dex_reserve
+        getProfileSupplyAllocation => profile_supply_allocation
+        getDexLiquidityReserve => dex_liquidity_reserve
+        getAirdropReserve => airdrop_reserve
+        createAirdrop => create_airdrop
+        claimAirdrop => claim_airdrop
+        reclaimAirdrop => reclaim_airdrop
+        getAirdrop => airdrop
+        isAirdropClaimed => airdrop_claimed
+        getTokenAirdrops => token_airdrops
+        setProfileCurve => set_profile_curve
+        clearProfileCurve => clear_profile_curve
+        getBuyQuote => get_buy_quote
+        simulateBuyCost => simulate_buy_cost
+        getProfileCurve => profile_curve
+        getBondingCurve => bonding_curve
+        getBondingCurveSupply => bonding_curve_supply
+        setBondingParam => set_bonding_param
+        setBondingParamForBondings => set_bonding_param_for_bondings
+        startBondingParamPush => start_bonding_param_push
+        continueBondingParamPush => continue_bonding_param_push
+        cancelBondingParamPush => cancel_bonding_param_push
+        getBondingParamPush => get_bonding_param_push
+        setFeesPullMode => set_fees_pull_mode
+        withdrawFees => withdraw_fees
+        recoverStrayFunds => recover_stray_funds
+        getStrayBalance => get_stray_balance
+        getFeeAccounts => get_fee_accounts
+        isFeesPullMode => fees_pull_mode
+        getFeeTokens => fee_tokens
+        getFeesAccrued => fees_accrued
+        getFeesWithdrawn => fees_withdrawn
+        getWithdrawableFees => withdrawable_fees
+        getOwedTokens => owed_tokens
+        getOwedBalance => owed_balance
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { master }